@@ -0,0 +1,487 @@
+use std::collections::{HashMap, HashSet};
+
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::contexts::use_i18n;
+use crate::csv::parse_csv;
+use crate::form::password_error;
+use crate::i18n::{self, Language};
+use crate::routes::Route;
+use crate::services::api::ApiService;
+use crate::services::error::ApiError;
+use crate::types::{Attendance, Choice, Hours, RegisterRequest, Role};
+use crate::utils::validate_email;
+
+// A data row of the uploaded file, validated before anything is sent
+#[derive(Debug, Clone)]
+struct ImportRow {
+    // 1-based line in the file, header included
+    line: usize,
+    request: RegisterRequest,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ImportResult {
+    Created,
+    Failed(String),
+}
+
+// Accepted header names for each field, in English, Spanish or Portuguese
+const COLUMNS: &[(&str, &[&str])] = &[
+    (
+        "email",
+        &["email", "e-mail", "correo", "correo electrónico"],
+    ),
+    (
+        "full_name",
+        &[
+            "full_name",
+            "name",
+            "nombre",
+            "nombre completo",
+            "nome",
+            "nome completo",
+        ],
+    ),
+    (
+        "identification",
+        &[
+            "identification",
+            "id",
+            "identificación",
+            "identificacion",
+            "documento",
+        ],
+    ),
+    (
+        "password",
+        &["password", "contraseña", "contrasena", "senha"],
+    ),
+    ("role", &["role", "rol", "função", "funcao"]),
+    ("presentation", &["presentation", "horas", "hours"]),
+    (
+        "attendance",
+        &["attendance", "asistencia", "participação", "participacao"],
+    ),
+];
+
+fn column_indexes(header: &[String]) -> Result<HashMap<&'static str, usize>, String> {
+    let mut indexes = HashMap::new();
+    for (field, aliases) in COLUMNS {
+        if let Some(index) = header
+            .iter()
+            .position(|name| aliases.contains(&name.trim().to_lowercase().as_str()))
+        {
+            indexes.insert(*field, index);
+        }
+    }
+
+    let missing: Vec<&str> = ["email", "full_name", "identification", "password"]
+        .into_iter()
+        .filter(|field| !indexes.contains_key(field))
+        .collect();
+    if missing.is_empty() {
+        Ok(indexes)
+    } else {
+        Err((i18n::messages().admin_import.missing_columns)(
+            &missing.join(", "),
+        ))
+    }
+}
+
+// Whether `value` is the key or the label, in any language, of a choice
+fn matches_choice<T: Choice>(
+    value: &str,
+    choice: T,
+    label_in: fn(&T, &'static i18n::Messages) -> &'static str,
+) -> bool {
+    value == choice.key()
+        || Language::ALL
+            .iter()
+            .any(|language| label_in(&choice, language.messages()).to_lowercase() == value)
+}
+
+// Accepts the key or a label; webmaster accounts are not imported
+fn normalize_role(value: &str) -> Option<Role> {
+    let value = value.trim().to_lowercase();
+    if value.is_empty() {
+        return Some(Role::Attendee);
+    }
+    [Role::Attendee, Role::Speaker, Role::Staff]
+        .into_iter()
+        .find(|role| matches_choice(&value, *role, Role::label_in))
+}
+
+fn normalize_attendance(value: &str) -> Option<Attendance> {
+    let value = value.trim().to_lowercase();
+    if value.is_empty() {
+        return Some(Attendance::Remote);
+    }
+    Attendance::ALL
+        .iter()
+        .copied()
+        .find(|attendance| matches_choice(&value, *attendance, Attendance::label_in))
+}
+
+fn parse_rows(text: &str) -> Result<Vec<ImportRow>, String> {
+    let t = &i18n::messages().admin_import;
+    let rows = parse_csv(text);
+    let Some((header, data)) = rows.split_first() else {
+        return Err(t.empty_file.to_string());
+    };
+    let indexes = column_indexes(&header.fields)?;
+    let mut seen_emails = HashSet::new();
+
+    Ok(data
+        .iter()
+        .map(|row| {
+            let fields = &row.fields;
+            let get = |field: &str| {
+                indexes
+                    .get(field)
+                    .and_then(|index| fields.get(*index))
+                    .map(|value| value.trim().to_string())
+                    .unwrap_or_default()
+            };
+            let mut errors = Vec::new();
+
+            let email = get("email");
+            if !validate_email(&email) {
+                errors.push(t.invalid_email.to_string());
+            } else if !seen_emails.insert(email.to_lowercase()) {
+                errors.push(t.repeated_email.to_string());
+            }
+
+            let full_name = get("full_name");
+            if full_name.is_empty() {
+                errors.push(t.missing_name.to_string());
+            }
+
+            let identification = get("identification");
+            if identification.is_empty() {
+                errors.push(t.missing_identification.to_string());
+            }
+
+            let password = get("password");
+            if let Some(error) = password_error(&password) {
+                errors.push(error);
+            }
+
+            let role = normalize_role(&get("role")).unwrap_or_else(|| {
+                errors.push((t.unknown_role)(&get("role")));
+                Role::Attendee
+            });
+
+            let presentation = if role == Role::Speaker {
+                Hours::parse(&get("presentation"))
+                    .map_err(|error| errors.push(error))
+                    .ok()
+            } else {
+                None
+            };
+
+            let attendance = normalize_attendance(&get("attendance")).unwrap_or_else(|| {
+                errors.push((t.unknown_attendance)(&get("attendance")));
+                Attendance::Remote
+            });
+
+            ImportRow {
+                line: row.line,
+                request: RegisterRequest {
+                    email,
+                    full_name,
+                    identification,
+                    password,
+                    role,
+                    presentation,
+                    attendance,
+                },
+                errors,
+            }
+        })
+        .collect())
+}
+
+#[function_component(AdminImport)]
+pub fn admin_import() -> Html {
+    let navigator = use_navigator().unwrap();
+    let rows = use_state(Vec::<ImportRow>::new);
+    let results = use_state(HashMap::<usize, ImportResult>::new);
+    let importing = use_state(|| false);
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = i18n.t();
+
+    let on_file_change = {
+        let rows = rows.clone();
+        let results = results.clone();
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            let rows = rows.clone();
+            let results = results.clone();
+            let message = message.clone();
+
+            spawn_local(async move {
+                let text = match JsFuture::from(file.text()).await {
+                    Ok(text) => text.as_string().unwrap_or_default(),
+                    Err(error) => {
+                        log::error!("Failed to read import file: {:?}", error);
+                        message.set(t.admin_import.read_error.to_string());
+                        return;
+                    }
+                };
+
+                results.set(HashMap::new());
+                match parse_rows(&text) {
+                    Ok(parsed) => {
+                        message.set(String::new());
+                        rows.set(parsed);
+                    }
+                    Err(error) => {
+                        rows.set(Vec::new());
+                        message.set(error);
+                    }
+                }
+            });
+        })
+    };
+
+    let on_import = {
+        let rows = rows.clone();
+        let results = results.clone();
+        let importing = importing.clone();
+        let message = message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let valid_rows: Vec<ImportRow> = rows
+                .iter()
+                .filter(|row| row.errors.is_empty())
+                .cloned()
+                .collect();
+            let results = results.clone();
+            let importing = importing.clone();
+            let message = message.clone();
+
+            importing.set(true);
+            spawn_local(async move {
+                // Sequential on purpose, so the backend is not flooded and
+                // progress is reported row by row
+                let mut done = HashMap::new();
+                for row in valid_rows {
+                    let result = match ApiService::register(row.request).await {
+                        Ok(_) => ImportResult::Created,
+                        Err(ApiError::Conflict(_)) => {
+                            ImportResult::Failed(t.admin_import.duplicate_email.to_string())
+                        }
+                        Err(error) => ImportResult::Failed(error.to_string()),
+                    };
+                    done.insert(row.line, result);
+                    results.set(done.clone());
+                }
+
+                let created = done
+                    .values()
+                    .filter(|result| **result == ImportResult::Created)
+                    .count();
+                message.set((t.admin_import.finished)(created, done.len() - created));
+                importing.set(false);
+            });
+        })
+    };
+
+    let on_back = {
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| navigator.push(&Route::AdminPanel))
+    };
+
+    let valid_count = rows.iter().filter(|row| row.errors.is_empty()).count();
+
+    html! {
+        <div class="container">
+            <h1>{t.admin_import.title}</h1>
+            <article>
+                <section>
+                    <p>{t.admin_import.instructions}</p>
+                    <div class="form-group">
+                        <label for="import-file-input">{t.admin_import.file_label}</label>
+                        <input
+                            type="file"
+                            id="import-file-input"
+                            accept=".csv,text/csv"
+                            disabled={*importing}
+                            onchange={on_file_change}
+                        />
+                    </div>
+                </section>
+
+                {if rows.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <section id="import-preview">
+                            <p>
+                                {(t.admin_import.summary)(valid_count, rows.len(), results.len())}
+                            </p>
+                            {if *importing {
+                                html! {
+                                    <progress max={valid_count.to_string()} value={results.len().to_string()} />
+                                }
+                            } else {
+                                html! {}
+                            }}
+                            <table>
+                                <thead>
+                                    <tr>
+                                        <th>{t.columns.line}</th>
+                                        <th>{t.columns.email}</th>
+                                        <th>{t.columns.name}</th>
+                                        <th>{t.columns.identification}</th>
+                                        <th>{t.columns.role}</th>
+                                        <th>{t.columns.hours}</th>
+                                        <th>{t.columns.attendance}</th>
+                                        <th>{t.columns.status}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {for rows.iter().map(|row| {
+                                        let status = match results.get(&row.line) {
+                                            Some(ImportResult::Created) => html! {
+                                                <span class="badge badge-success">{t.admin_import.created}</span>
+                                            },
+                                            Some(ImportResult::Failed(error)) => html! {
+                                                <span class="badge badge-danger">{error}</span>
+                                            },
+                                            None if row.errors.is_empty() => html! {
+                                                <span class="badge">{t.admin_import.ready}</span>
+                                            },
+                                            None => html! {
+                                                <span class="badge badge-danger">{row.errors.join("; ")}</span>
+                                            },
+                                        };
+                                        html! {
+                                            <tr key={row.line}>
+                                                <td>{row.line}</td>
+                                                <td>{&row.request.email}</td>
+                                                <td>{&row.request.full_name}</td>
+                                                <td>{&row.request.identification}</td>
+                                                <td>{row.request.role.label()}</td>
+                                                <td>{row.request.presentation.map(|hours| hours.to_string()).unwrap_or_default()}</td>
+                                                <td>{row.request.attendance.label()}</td>
+                                                <td>{status}</td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+                        </section>
+                    }
+                }}
+
+                <div>
+                    <span id="message-span">{(*message).clone()}</span>
+                </div>
+
+                <section class="admin-actions">
+                    <button
+                        id="import-btn"
+                        class="btn"
+                        onclick={on_import}
+                        disabled={*importing || valid_count == 0 || !results.is_empty()}
+                    >
+                        {(t.admin_import.submit)(valid_count)}
+                    </button>
+                    <button class="btn btn-outline" onclick={on_back} disabled={*importing}>
+                        {t.admin_import.back}
+                    </button>
+                </section>
+            </article>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "Secreta_123";
+
+    #[test]
+    fn accepts_header_aliases_in_any_language() {
+        let text = format!(
+            "Correo;Nome completo;Documento;Senha;Role;Hours;Participação\n\
+             ana@example.com;Ana;123;{};palestrante;1,5;Presencial\n",
+            PASSWORD
+        );
+        let rows = parse_rows(&text).unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let row = &rows[0];
+        assert!(row.errors.is_empty(), "{:?}", row.errors);
+        assert_eq!(row.line, 2);
+        assert_eq!(row.request.email, "ana@example.com");
+        assert_eq!(row.request.full_name, "Ana");
+        assert_eq!(row.request.identification, "123");
+        assert_eq!(row.request.role, Role::Speaker);
+        assert_eq!(row.request.presentation, Hours::parse("1.5").ok());
+        assert_eq!(row.request.attendance, Attendance::Presential);
+    }
+
+    #[test]
+    fn defaults_optional_columns() {
+        let text = format!(
+            "email,name,id,password\nana@example.com,Ana,123,{}\n",
+            PASSWORD
+        );
+        let row = &parse_rows(&text).unwrap()[0];
+        assert!(row.errors.is_empty(), "{:?}", row.errors);
+        assert_eq!(row.request.role, Role::Attendee);
+        assert_eq!(row.request.presentation, None);
+        assert_eq!(row.request.attendance, Attendance::Remote);
+    }
+
+    #[test]
+    fn reports_missing_columns() {
+        assert!(parse_rows("email,name\na@b.co,Ana\n").is_err());
+        assert!(parse_rows("").is_err());
+    }
+
+    #[test]
+    fn flags_invalid_rows() {
+        let text = format!(
+            "email,name,id,password,role,attendance\n\
+             ana@example.com,Ana,1,{p},webmaster,remote\n\
+             ANA@example.com,Ana,2,{p},staff,hybrid\n\
+             not-an-email,,,short,,\n",
+            p = PASSWORD
+        );
+        let rows = parse_rows(&text).unwrap();
+        let error_counts: Vec<usize> = rows.iter().map(|row| row.errors.len()).collect();
+        // Unknown role; repeated email and unknown attendance; invalid email,
+        // name, identification and password
+        assert_eq!(error_counts, [1, 2, 4]);
+    }
+
+    #[test]
+    fn reports_file_lines_across_blank_lines_and_quoted_breaks() {
+        let text = format!(
+            "email,name,id,password\n\
+             \n\
+             ana@example.com,\"Ana\nMaría\",1,{p}\n\
+             luis@example.com,Luis,2,{p}\n",
+            p = PASSWORD
+        );
+        let lines: Vec<usize> = parse_rows(&text)
+            .unwrap()
+            .iter()
+            .map(|row| row.line)
+            .collect();
+        assert_eq!(lines, [3, 5]);
+    }
+}
@@ -1,39 +1,65 @@
+use std::collections::HashSet;
+
+use gloo_timers::future::TimeoutFuture;
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::components::{BulkActions, Pager, UserFilters};
+use crate::contexts::{use_i18n, use_user_cache, UserCacheContext};
+use crate::csv::{users_to_csv, CsvFormat};
 use crate::routes::Route;
 use crate::services::api::ApiService;
-use crate::types::{DeleteUserRequest, User, UserRole};
+use crate::services::error::ApiError;
+use crate::types::{Choice, User, UserQuery};
+use crate::utils::download_file;
+
+const PAGE_SIZE: u32 = 50;
+// Exports fetch every page of the current filters, in bigger pages
+const EXPORT_PAGE_SIZE: u32 = 500;
+// How long the "undo" option stays available after a deletion
+const UNDO_WINDOW_MS: u32 = 10_000;
+
+// Fetches a page and stores it in the shared cache; a cached copy is shown
+// meanwhile, so this doubles as revalidation
+async fn load_page(query: UserQuery, cache: UserCacheContext, message: UseStateHandle<String>) {
+    match ApiService::admin_get_users_page(&query, PAGE_SIZE).await {
+        Ok(user_page) => {
+            cache.store_page(query, user_page);
+        }
+        Err(error) => {
+            message.set(error.to_string());
+        }
+    }
+}
 
 #[function_component(AdminPanel)]
 pub fn admin_users() -> Html {
     let navigator = use_navigator().expect("Navigator not found");
-    let users = use_state(|| Vec::<User>::new());
-    let message = use_state(|| String::new());
-    let deleting_user = use_state(|| None::<String>);
+    let query = use_location()
+        .and_then(|location| location.query::<UserQuery>().ok())
+        .unwrap_or_default();
+    let cache = use_user_cache();
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = i18n.t();
+    let selected = use_state(HashSet::<String>::new);
+    let undo_user = use_state(|| None::<User>);
+    // Id of the most recent deletion; state handles captured by the undo
+    // timer would only see the value from when it started
+    let last_deleted = use_mut_ref(|| None::<String>);
+    let exporting = use_state(|| false);
 
-    // Load users on component mount
+    // Load the requested page on mount and whenever the filters change
     {
-        let users = users.clone();
+        let cache = cache.clone();
         let message = message.clone();
         use_effect_with_deps(
-            move |_| {
-                let users = users.clone();
-                let message = message.clone();
-                spawn_local(async move {
-                    match ApiService::admin_get_users().await {
-                        Ok(user_list) => {
-                            users.set(user_list);
-                        }
-                        Err(error) => {
-                            message.set(error);
-                        }
-                    }
-                });
+            move |query: &UserQuery| {
+                spawn_local(load_page(query.clone(), cache, message));
                 || ()
             },
-            (),
+            query.clone(),
         );
     }
 
@@ -44,184 +70,375 @@ pub fn admin_users() -> Html {
         })
     };
 
+    let on_import_click = {
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| {
+            navigator.push(&Route::AdminImport);
+        })
+    };
+
     let on_reload_click = {
-        let users = users.clone();
+        let query = query.clone();
+        let cache = cache.clone();
         let message = message.clone();
         Callback::from(move |_: MouseEvent| {
-            let users = users.clone();
-            let message = message.clone();
-            spawn_local(async move {
-                match ApiService::admin_get_users().await {
-                    Ok(user_list) => {
-                        users.set(user_list);
-                    }
-                    Err(error) => {
-                        message.set(error);
-                    }
-                }
-            });
+            spawn_local(load_page(query.clone(), cache.clone(), message.clone()));
         })
     };
 
     let on_delete_click = {
-        let users = users.clone();
+        let query = query.clone();
+        let cache = cache.clone();
         let message = message.clone();
-        let deleting_user = deleting_user.clone();
+        let undo_user = undo_user.clone();
+        let last_deleted = last_deleted.clone();
 
         Callback::from(move |user: User| {
-            let users = users.clone();
+            let query = query.clone();
+            let cache = cache.clone();
             let message = message.clone();
-            let deleting_user = deleting_user.clone();
+            let undo_user = undo_user.clone();
+            let last_deleted = last_deleted.clone();
 
             // Confirm deletion
             if gloo_utils::window()
-                .confirm_with_message(&format!(
-                    "¿Está seguro que desea eliminar al usuario {}?",
-                    user.full_name
-                ))
+                .confirm_with_message(&(t.admin_panel.delete_confirm)(&user.full_name))
                 .unwrap_or(false)
             {
-                deleting_user.set(Some(user.id.clone()));
-
-                let delete_request = DeleteUserRequest {
-                    email: user.email,
-                    full_name: user.full_name,
-                    identification: user.identification,
-                    password: String::new(), // This might need to be handled differently
-                    role: match user.role {
-                        UserRole::Simple(ref role) => role.clone(),
-                        UserRole::Speaker { .. } => "speaker".to_string(),
-                    },
-                    presentation: user.presentation.unwrap_or(String::new()),
-                    attendance: user.attendance,
-                };
+                // Hide the row right away and put it back if the request fails
+                cache.remove_user(&user.id);
 
                 spawn_local(async move {
-                    match ApiService::delete_user(delete_request).await {
+                    match ApiService::delete_user(&user.id).await {
                         Ok(_) => {
-                            // Reload users after successful deletion
-                            match ApiService::admin_get_users().await {
-                                Ok(user_list) => {
-                                    users.set(user_list);
-                                    message.set("Usuario eliminado exitosamente".to_string());
-                                }
-                                Err(error) => {
-                                    message.set(error);
-                                }
+                            message.set(String::new());
+                            undo_user.set(Some(user.clone()));
+                            *last_deleted.borrow_mut() = Some(user.id.clone());
+                            load_page(query, cache, message).await;
+
+                            TimeoutFuture::new(UNDO_WINDOW_MS).await;
+                            // Only hide the option if no newer deletion replaced it
+                            if last_deleted.borrow().as_ref() == Some(&user.id) {
+                                undo_user.set(None);
                             }
                         }
+                        Err(ApiError::NotFound) => {
+                            message.set(t.admin_panel.already_deleted.to_string());
+                            load_page(query, cache, message.clone()).await;
+                        }
+                        Err(ApiError::Conflict(_)) => {
+                            cache.store_user(user);
+                            message.set(t.admin_panel.delete_conflict.to_string());
+                        }
                         Err(error) => {
-                            message.set(format!("Error al eliminar usuario: {}", error));
+                            cache.store_user(user);
+                            message.set((t.admin_panel.delete_error)(&error.to_string()));
                         }
                     }
-                    deleting_user.set(None);
                 });
             }
         })
     };
 
-    // Helper functions
-    let get_role_display = |user: &User| -> (String, String) {
-        match &user.role {
-            UserRole::Simple(role_str) => {
-                let display = match role_str.as_str() {
-                    "webmaster" => "Administrador",
-                    "staff" => "Organizador",
-                    "attendee" => "Asistente",
-                    _ => "Asistente",
-                };
-                (display.to_string(), "-".to_string())
-            }
-            UserRole::Speaker { speaker } => {
-                ("Ponente".to_string(), speaker.presentation.to_string())
+    let on_undo_click = {
+        let query = query.clone();
+        let cache = cache.clone();
+        let message = message.clone();
+        let undo_user = undo_user.clone();
+        let last_deleted = last_deleted.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let Some(user) = (*undo_user).clone() else {
+                return;
+            };
+            let query = query.clone();
+            let cache = cache.clone();
+            let message = message.clone();
+
+            undo_user.set(None);
+            *last_deleted.borrow_mut() = None;
+            cache.store_user(user.clone());
+            spawn_local(async move {
+                match ApiService::restore_user(&user.id).await {
+                    Ok(_) => {
+                        message.set((t.admin_panel.restored)(&user.full_name));
+                        load_page(query, cache, message).await;
+                    }
+                    Err(error) => {
+                        cache.remove_user(&user.id);
+                        message.set((t.admin_panel.restore_error)(&error.to_string()));
+                    }
+                }
+            });
+        })
+    };
+
+    let on_query_change = {
+        let navigator = navigator.clone();
+        Callback::from(move |query: UserQuery| {
+            if let Err(error) = navigator.replace_with_query(&Route::AdminPanel, &query) {
+                log::error!("Failed to update filters in URL: {}", error);
             }
-        }
+        })
     };
 
-    let get_attendance_display = |attendance: &str| -> &str {
-        match attendance {
-            "presential" => "Presencial",
-            _ => "Remota",
+    let sort_header = |column: &'static str, label: &'static str| {
+        let on_click = {
+            let on_query_change = on_query_change.clone();
+            let next = query.toggle_sort(column);
+            Callback::from(move |_: MouseEvent| on_query_change.emit(next.clone()))
+        };
+        let aria_sort = match (query.sort.as_deref() == Some(column), query.desc) {
+            (true, false) => "ascending",
+            (true, true) => "descending",
+            (false, _) => "none",
+        };
+
+        html! {
+            <th rowspan="2" aria-sort={aria_sort}>
+                <button type="button" class="sort-btn" onclick={on_click}>
+                    {label}{query.sort_indicator(column)}
+                </button>
+            </th>
         }
     };
 
+    let page = cache.page(&query);
+    let users = page
+        .as_ref()
+        .map(|page| page.items.clone())
+        .unwrap_or_default();
+    let total = page.as_ref().map_or(0, |page| page.total);
+    let total_pages = page.as_ref().map_or(1, |page| page.total_pages());
+    let visible_users = query.apply(&users);
+
+    let on_export = {
+        let query = query.clone();
+        let message = message.clone();
+        let exporting = exporting.clone();
+        Callback::from(move |format: CsvFormat| {
+            let date = String::from(js_sys::Date::new_0().to_iso_string());
+            let (suffix, content_type) = match format {
+                CsvFormat::Standard => ("", "text/csv;charset=utf-8"),
+                CsvFormat::Excel => ("_excel", "application/vnd.ms-excel;charset=utf-8"),
+            };
+            let filename = format!(
+                "{}_{}{}.csv",
+                t.admin_panel.export_filename,
+                &date[..10],
+                suffix
+            );
+            let query = query.clone();
+            let message = message.clone();
+            let exporting = exporting.clone();
+
+            exporting.set(true);
+            spawn_local(async move {
+                // The table only holds the current page
+                match ApiService::admin_get_all_users(&query, EXPORT_PAGE_SIZE).await {
+                    Ok(users) => {
+                        let csv = users_to_csv(&query.apply(&users), format);
+                        if let Err(error) = download_file(csv.as_bytes(), content_type, &filename) {
+                            log::error!("Failed to export users: {:?}", error);
+                            message.set(t.admin_panel.export_error.to_string());
+                        }
+                    }
+                    Err(error) => {
+                        message.set(error.to_string());
+                    }
+                }
+                exporting.set(false);
+            });
+        })
+    };
+    let on_export_csv = {
+        let on_export = on_export.clone();
+        Callback::from(move |_: MouseEvent| on_export.emit(CsvFormat::Standard))
+    };
+    let on_export_excel = {
+        let on_export = on_export.clone();
+        Callback::from(move |_: MouseEvent| on_export.emit(CsvFormat::Excel))
+    };
+
+    // Selection is limited to the rows on screen
+    let selected_users: Vec<User> = visible_users
+        .iter()
+        .filter(|user| selected.contains(&user.id))
+        .cloned()
+        .collect();
+    let all_selected = !visible_users.is_empty() && selected_users.len() == visible_users.len();
+
+    let on_select_all = {
+        let selected = selected.clone();
+        let visible_ids: HashSet<String> =
+            visible_users.iter().map(|user| user.id.clone()).collect();
+        Callback::from(move |_: Event| {
+            if all_selected {
+                selected.set(HashSet::new());
+            } else {
+                selected.set(visible_ids.clone());
+            }
+        })
+    };
+
+    let on_select_user = {
+        let selected = selected.clone();
+        Callback::from(move |id: String| {
+            let mut current = (*selected).clone();
+            if !current.remove(&id) {
+                current.insert(id);
+            }
+            selected.set(current);
+        })
+    };
+
+    let on_bulk_done = {
+        let query = query.clone();
+        let cache = cache.clone();
+        let message = message.clone();
+        let selected = selected.clone();
+        Callback::from(move |_| {
+            selected.set(HashSet::new());
+            // Bulk changes can move users between listings
+            cache.invalidate();
+            spawn_local(load_page(query.clone(), cache.clone(), message.clone()));
+        })
+    };
+
+    let on_page_change = {
+        let on_query_change = on_query_change.clone();
+        let query = query.clone();
+        Callback::from(move |page: u32| on_query_change.emit(query.with_page(page)))
+    };
+
+    // Helper functions
     let get_cert_display = |generated: bool| -> &str {
         if generated {
-            "Sí"
+            t.common.yes
         } else {
-            "No"
+            t.common.no
         }
     };
 
     html! {
         <div class="container">
-            <h1>{"Administrar usuarios"}</h1>
+            <h1>{t.admin_panel.title}</h1>
             <article>
                 <section id="buttons-section" class="admin-actions">
-                    <button id="register-btn" class="btn" onclick={on_register_click}>{"Registrar"}</button>
-                    <button id="reload-btn" class="btn btn-outline" onclick={on_reload_click}>{"Actualizar lista"}</button>
+                    <button id="register-btn" class="btn" onclick={on_register_click}>{t.admin_panel.register}</button>
+                    <button id="import-btn" class="btn btn-outline" onclick={on_import_click}>{t.admin_panel.import}</button>
+                    <button id="reload-btn" class="btn btn-outline" onclick={on_reload_click}>{t.admin_panel.reload}</button>
+                    <button
+                        id="export-csv-btn"
+                        class="btn btn-outline"
+                        onclick={on_export_csv}
+                        disabled={visible_users.is_empty() || *exporting}
+                    >
+                        {t.admin_panel.export_csv}
+                    </button>
+                    <button
+                        id="export-excel-btn"
+                        class="btn btn-outline"
+                        onclick={on_export_excel}
+                        disabled={visible_users.is_empty() || *exporting}
+                    >
+                        {t.admin_panel.export_excel}
+                    </button>
                 </section>
 
+                <UserFilters query={query.clone()} on_change={on_query_change.clone()} />
+                <p id="results-count">
+                    {(t.admin_panel.showing)(visible_users.len(), total)}
+                </p>
+
+                <BulkActions selected={selected_users} on_done={on_bulk_done} />
+
                 <section id="table-section">
                     <table>
                         <thead>
                             <tr>
-                                <th rowspan="2">{"SID"}</th>
-                                <th rowspan="2">{"E-mail"}</th>
-                                <th rowspan="2">{"Nombre"}</th>
-                                <th rowspan="2">{"Identificación"}</th>
-                                <th rowspan="2">{"Rol"}</th>
-                                <th rowspan="2">{"Horas"}</th>
-                                <th rowspan="2">{"Asistencia"}</th>
-                                <th colspan="2">{"Constancias generadas"}</th>
-                                <th rowspan="2">{"Acciones"}</th>
+                                <th rowspan="2">
+                                    <input
+                                        type="checkbox"
+                                        aria-label={t.admin_panel.select_all}
+                                        checked={all_selected}
+                                        onchange={on_select_all}
+                                    />
+                                </th>
+                                {sort_header("id", t.columns.id)}
+                                {sort_header("email", t.columns.email)}
+                                {sort_header("name", t.columns.name)}
+                                {sort_header("identification", t.columns.identification)}
+                                {sort_header("role", t.columns.role)}
+                                {sort_header("hours", t.columns.hours)}
+                                {sort_header("attendance", t.columns.attendance)}
+                                <th colspan="2">{t.columns.certificates}</th>
+                                <th rowspan="2">{t.columns.actions}</th>
                             </tr>
                             <tr>
-                                <th>{"Horizontal"}</th>
-                                <th>{"Vertical"}</th>
+                                <th>{t.columns.horizontal}</th>
+                                <th>{t.columns.vertical}</th>
                             </tr>
                         </thead>
                         <tbody id="user-list">
                             {
-                                if users.is_empty() {
+                                if page.is_none() {
                                     html! {
                                         <tr>
-                                            <td colspan="10">{"Todavía no hay usuarios"}</td>
+                                            <td colspan="11">{t.admin_panel.loading}</td>
+                                        </tr>
+                                    }
+                                } else if total == 0 && !query.is_filtered() {
+                                    html! {
+                                        <tr>
+                                            <td colspan="11">{t.admin_panel.empty}</td>
+                                        </tr>
+                                    }
+                                } else if visible_users.is_empty() {
+                                    html! {
+                                        <tr>
+                                            <td colspan="11">{t.admin_panel.no_matches}</td>
                                         </tr>
                                     }
                                 } else {
-                                    users.iter().map(|user| {
-                                        let (role_display, presentation_display) = get_role_display(user);
+                                    visible_users.iter().map(|user| {
                                         let user_clone = user.clone();
                                         let on_delete = on_delete_click.clone();
-                                        let is_deleting = deleting_user.as_ref() == Some(&user.id);
+                                        let on_select = {
+                                            let on_select_user = on_select_user.clone();
+                                            let id = user.id.clone();
+                                            Callback::from(move |_: Event| on_select_user.emit(id.clone()))
+                                        };
 
                                         html! {
                                             <tr key={user.id.clone()}>
+                                                <td>
+                                                    <input
+                                                        type="checkbox"
+                                                        aria-label={(t.admin_panel.select_user)(&user.full_name)}
+                                                        checked={selected.contains(&user.id)}
+                                                        onchange={on_select}
+                                                    />
+                                                </td>
                                                 <td>{&user.id}</td>
                                                 <td>{&user.email}</td>
                                                 <td>{&user.full_name}</td>
                                                 <td>{&user.identification}</td>
-                                                <td>{role_display}</td>
-                                                <td>{presentation_display}</td>
-                                                <td>{get_attendance_display(&user.attendance)}</td>
+                                                <td>{user.role().label()}</td>
+                                                <td>{user.speaker_hours().map_or("-".to_string(), |hours| hours.to_string())}</td>
+                                                <td>{user.attendance.label()}</td>
                                                 <td>{get_cert_display(user.cert_generated.horizontal)}</td>
                                                 <td>{get_cert_display(user.cert_generated.vertical)}</td>
                                                 <td>
                                                     <div class="admin-actions" style="gap: 0.5rem;">
                                                         <Link<Route> to={Route::AdminUpdate { id: user.id.clone() }}>
-                                                            <button class="btn btn-sm">{"Editar"}</button>
+                                                            <button class="btn btn-sm">{t.admin_panel.edit}</button>
                                                         </Link<Route>>
                                                         <button
                                                             class="btn btn-danger btn-sm"
                                                             onclick={move |_| on_delete.emit(user_clone.clone())}
-                                                            disabled={is_deleting}
                                                         >
-                                                            {if is_deleting {
-                                                                html! { <span class="spinner" style="width: 1rem; height: 1rem;"></span> }
-                                                            } else {
-                                                                html! { {"Eliminar"} }
-                                                            }}
+                                                            {t.admin_panel.delete}
                                                         </button>
                                                     </div>
                                                 </td>
@@ -234,6 +451,21 @@ pub fn admin_users() -> Html {
                     </table>
                 </section>
 
+                <Pager page={query.current_page()} {total_pages} on_change={on_page_change} />
+
+                {if let Some(user) = undo_user.as_ref() {
+                    html! {
+                        <div id="undo-section" class="admin-actions" role="status">
+                            <span>{(t.admin_panel.deleted)(&user.full_name)}</span>
+                            <button id="undo-btn" class="btn btn-sm btn-outline" onclick={on_undo_click}>
+                                {t.admin_panel.undo}
+                            </button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 {if !message.is_empty() {
                     html! {
                         <div>
@@ -1,179 +1,110 @@
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use crate::components::register::{
+    registration_request, registration_values, validate_registration,
+};
+use crate::components::{choice_options, PasswordField, SelectField, TextField};
+use crate::contexts::use_i18n;
+use crate::form::use_form;
 use crate::services::api::ApiService;
-use crate::types::RegisterRequest;
-use crate::utils::validate_password;
+use crate::services::error::ApiError;
+use crate::types::{Attendance, Choice, RegisterRequest, Role};
+use crate::utils::generate_password;
 
 #[function_component(AdminRegister)]
 pub fn admin_register() -> Html {
-    let email = use_state(|| String::new());
-    let full_name = use_state(|| String::new());
-    let identification = use_state(|| String::new());
-    let password = use_state(|| String::new());
-    let repeated_password = use_state(|| String::new());
-    let role = use_state(|| "attendee".to_string());
-    let presentation = use_state(|| String::new());
-    let attendance = use_state(|| "remote".to_string());
-    let message = use_state(|| String::new());
-
-    // Test data population (similar to the original JS test function)
-    {
-        let email = email.clone();
-        let full_name = full_name.clone();
-        let identification = identification.clone();
-        let password = password.clone();
-        let repeated_password = repeated_password.clone();
-        let role = role.clone();
-        let presentation = presentation.clone();
-        let attendance = attendance.clone();
-
-        use_effect_with_deps(
-            move |_| {
-                // Populate test data
-                email.set("test@gmail.com".to_string());
-                full_name.set("Test Example".to_string());
-                identification.set("1234567890".to_string());
-                password.set("T3stexampl*".to_string());
-                repeated_password.set("T3stexampl*".to_string());
-                role.set("attendee".to_string());
-                presentation.set(String::new());
-                attendance.set("remote".to_string());
-                || ()
-            },
-            (),
-        );
-    }
-
-    let on_email_change = {
-        let email = email.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            email.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_full_name_change = {
-        let full_name = full_name.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            full_name.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_identification_change = {
-        let identification = identification.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            identification.set(input.value());
-            message.set(String::new());
-        })
+    let form = use_form(
+        || registration_values(&RegisterRequest::default()),
+        validate_registration,
+    );
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = i18n.t();
+    // Shown once after "Generar contraseña" so it can be handed to the user
+    let generated_password = use_state(|| None::<String>);
+
+    #[cfg(feature = "dev-fixtures")]
+    let fixture_button = {
+        let form = form.clone();
+        let generated_password = generated_password.clone();
+        let on_click = Callback::from(move |_: MouseEvent| {
+            let data = crate::fixtures::sample_registration();
+            generated_password.set(Some(data.password.clone()));
+            form.reset(registration_values(&data));
+        });
+
+        html! {
+            <button type="button" id="fixture-btn" class="btn btn-outline" onclick={on_click}>
+                {t.admin_register.fixture}
+            </button>
+        }
     };
+    #[cfg(not(feature = "dev-fixtures"))]
+    let fixture_button = html! {};
 
-    let on_password_change = {
-        let password = password.clone();
+    let on_generate_password = {
+        let form = form.clone();
+        let generated_password = generated_password.clone();
         let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            password.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_repeated_password_change = {
-        let repeated_password = repeated_password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            repeated_password.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_role_change = {
-        let role = role.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            role.set(select.value());
+        Callback::from(move |_: MouseEvent| match generate_password(12) {
+            Ok(value) => {
+                form.set("password", value.clone());
+                form.set("repeated_password", value.clone());
+                generated_password.set(Some(value));
+                message.set(String::new());
+            }
+            Err(error) => {
+                log::error!("Could not generate password: {:?}", error);
+                message.set(t.admin_register.generate_error.to_string());
+            }
         })
     };
 
-    let on_presentation_change = {
-        let presentation = presentation.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            presentation.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_attendance_change = {
-        let attendance = attendance.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            attendance.set(select.value());
-        })
-    };
+    // Only while the password field still holds it; typing a different one
+    // hides it
+    let shown_password = generated_password
+        .as_ref()
+        .filter(|value| **value == form.value("password"))
+        .cloned();
 
     let on_submit = {
-        let email = email.clone();
-        let full_name = full_name.clone();
-        let identification = identification.clone();
-        let password = password.clone();
-        let repeated_password = repeated_password.clone();
-        let role = role.clone();
-        let presentation = presentation.clone();
-        let attendance = attendance.clone();
+        let form = form.clone();
+        let generated_password = generated_password.clone();
         let message = message.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let email_val = (*email).clone();
-            let full_name_val = (*full_name).clone();
-            let identification_val = (*identification).clone();
-            let password_val = (*password).clone();
-            let repeated_password_val = (*repeated_password).clone();
-            let role_val = (*role).clone();
-            let presentation_val = (*presentation).clone();
-            let attendance_val = (*attendance).clone();
-            let message = message.clone();
-
-            if !validate_password(&password_val) || !validate_password(&repeated_password_val) {
-                message.set("Contraseña no valida".to_string());
+            let Some(values) = form.submit() else {
+                message.set(String::new());
                 return;
-            }
-
-            if password_val != repeated_password_val {
-                message.set("Contraseñas no coinciden".to_string());
-                return;
-            }
-
-            let data = RegisterRequest {
-                email: email_val,
-                full_name: full_name_val,
-                identification: identification_val,
-                password: password_val,
-                role: role_val,
-                presentation: presentation_val,
-                attendance: attendance_val,
             };
+            let data = registration_request(&values);
+            let form = form.clone();
+            let generated_password = generated_password.clone();
+            let message = message.clone();
 
             spawn_local(async move {
+                let password = data.password.clone();
                 match ApiService::register(data).await {
                     Ok(response) => {
                         log::info!("Success: {}", response);
-                        message.set("Usuario registrado exitosamente".to_string());
+                        form.reset(registration_values(&RegisterRequest::default()));
+                        // Last chance to copy a generated password
+                        match (*generated_password).as_ref() {
+                            Some(generated) if *generated == password => {
+                                message.set((t.admin_register.success_with_password)(generated))
+                            }
+                            _ => message.set(t.register.success.to_string()),
+                        }
+                        generated_password.set(None);
+                    }
+                    Err(ApiError::Conflict(_)) => {
+                        message.set(t.common.duplicate_user.to_string());
                     }
                     Err(error) => {
-                        message.set(error);
+                        message.set(error.to_string());
                     }
                 }
             });
@@ -182,103 +113,102 @@ pub fn admin_register() -> Html {
 
     html! {
         <>
-            <h1>{"Registro de usuarios"}</h1>
+            <h1>{t.admin_register.title}</h1>
 
             <section>
-                <form id="register-form" onsubmit={on_submit}>
-                    <div class="form-group">
-                        <label for="email-input">{"Correo electrónico:"}</label>
-                        <input
-                            type="email"
-                            id="email-input"
-                            class="form-input"
-                            required={true}
-                            minlength="5"
-                            value={(*email).clone()}
-                            onchange={on_email_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="full-name-input">{"Nombre completo:"}</label>
-                        <input
-                            type="text"
-                            id="full-name-input"
-                            class="form-input"
-                            required={true}
-                            value={(*full_name).clone()}
-                            onchange={on_full_name_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="id-input">{"Documento de identificación:"}</label>
-                        <input
-                            type="text"
-                            id="id-input"
-                            class="form-input"
-                            required={true}
-                            value={(*identification).clone()}
-                            onchange={on_identification_change}
-                        />
-                    </div>
-
-                    <div class="form-group">
-                        <label for="password-input">{"Contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*password).clone()}
-                            onchange={on_password_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="repeat-password-input">{"Repita contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="repeat-password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*repeated_password).clone()}
-                            onchange={on_repeated_password_change}
-                        />
-                    </div>
-
-                    <div class="form-group">
-                        <label for="role-select">{"Rol:"}</label>
-                        <select id="role-select" value={(*role).clone()} onchange={on_role_change}>
-                            <option value="attendee">{"Asistente"}</option>
-                            <option value="speaker">{"Ponente"}</option>
-                            <option value="staff">{"Organizador"}</option>
-                        </select>
-                    </div>
-
-                    <div class={if *role != "speaker" { "form-group hidden" } else { "form-group" }}>
-                        <label for="hours-input">{"Cantidad de horas de la ponencia (0 por defecto):"}</label>
-                        <input
-                            type="number"
-                            id="hours-input"
-                            class="form-input"
-                            value={presentation.to_string()}
-                            onchange={on_presentation_change}
-                        />
-                    </div>
-
+                {fixture_button}
+                <form id="register-form" novalidate={true} onsubmit={on_submit}>
+                    <TextField
+                        form={form.clone()}
+                        name="email"
+                        id="email-input"
+                        label={t.fields.email}
+                        input_type="email"
+                        required={true}
+                        minlength="5"
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="full_name"
+                        id="full-name-input"
+                        label={t.fields.full_name}
+                        required={true}
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="identification"
+                        id="id-input"
+                        label={t.fields.identification}
+                        required={true}
+                    />
+
+                    <PasswordField
+                        form={form.clone()}
+                        name="password"
+                        id="password-input"
+                        label={t.fields.password}
+                        required={true}
+                        minlength="8"
+                    />
+                    <PasswordField
+                        form={form.clone()}
+                        name="repeated_password"
+                        id="repeat-password-input"
+                        label={t.fields.repeat_password}
+                        required={true}
+                        minlength="8"
+                    />
                     <div class="form-group">
-                        <label for="attendance-select">{"Tipo de asistencia:"}</label>
-                        <select id="attendance-select" value={(*attendance).clone()} onchange={on_attendance_change}>
-                            <option value="remote">{"Remota"}</option>
-                            <option value="presential">{"Presencial"}</option>
-                        </select>
+                        <button type="button" id="generate-password-btn" class="btn btn-outline" onclick={on_generate_password}>
+                            {t.admin_register.generate_password}
+                        </button>
                     </div>
+                    {if let Some(value) = shown_password {
+                        html! {
+                            <p id="generated-password" class="notice" role="status">
+                                {t.admin_register.generated_before}<code>{value}</code>
+                                {t.admin_register.generated_after}
+                            </p>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    <SelectField
+                        form={form.clone()}
+                        name="role"
+                        id="role-select"
+                        label={t.fields.role}
+                        options={choice_options(&[Role::Attendee, Role::Speaker, Role::Staff])}
+                    />
+
+                    {if form.values().choice::<Role>("role") == Role::Speaker {
+                        html! {
+                            <TextField
+                                form={form.clone()}
+                                name="presentation"
+                                id="hours-input"
+                                label={t.fields.hours}
+                                inputmode="decimal"
+                            />
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    <SelectField
+                        form={form.clone()}
+                        name="attendance"
+                        id="attendance-select"
+                        label={t.fields.attendance}
+                        options={choice_options(Attendance::ALL)}
+                    />
 
                     <div>
                         <span id="message-span">{(*message).clone()}</span>
                     </div>
 
-                    <button type="submit" id="register-btn">{"Registrarse"}</button>
+                    <button type="submit" id="register-btn">{t.register.submit}</button>
                 </form>
             </section>
         </>
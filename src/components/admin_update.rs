@@ -1,18 +1,96 @@
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::components::{choice_options, PasswordField, SelectField, TextField};
+use crate::contexts::{use_i18n, use_user_cache};
+use crate::form::{
+    add_error, choice_error, email_error, hours_error, password_error, required_error, use_form,
+    FormErrors, FormValues,
+};
 use crate::routes::Route;
 use crate::services::api::ApiService;
-use crate::types::{AdminUpdateUserRequest, User};
-use crate::utils::validate_password;
+use crate::services::error::ApiError;
+use crate::types::{AdminUpdateUserRequest, Attendance, Choice, Role, User};
 
 #[derive(Properties, PartialEq)]
 pub struct AdminUpdateProps {
     pub user_id: String,
 }
 
+// The password is left empty: it only changes when the admin types one
+fn user_values(user: &User) -> FormValues {
+    let presentation = user
+        .speaker_hours()
+        .map(|hours| hours.key())
+        .unwrap_or_default();
+
+    FormValues::new(&[
+        ("email", &user.email),
+        ("full_name", &user.full_name),
+        ("identification", &user.identification),
+        ("password", ""),
+        ("role", user.role().key()),
+        ("presentation", &presentation),
+        ("attendance", user.attendance.key()),
+    ])
+}
+
+fn validate_update(values: &FormValues) -> FormErrors {
+    let mut errors = FormErrors::new();
+    let password = values.get("password");
+
+    add_error(&mut errors, "email", email_error(values.get("email")));
+    add_error(
+        &mut errors,
+        "full_name",
+        required_error(values.get("full_name")),
+    );
+    add_error(
+        &mut errors,
+        "identification",
+        required_error(values.get("identification")),
+    );
+    // Empty keeps the current password
+    if !password.is_empty() {
+        add_error(&mut errors, "password", password_error(password));
+    }
+    // An unrecognized role or attendance must be replaced explicitly; saving
+    // would otherwise overwrite it with the select's default
+    add_error(
+        &mut errors,
+        "role",
+        choice_error::<Role>(values.get("role")),
+    );
+    add_error(
+        &mut errors,
+        "attendance",
+        choice_error::<Attendance>(values.get("attendance")),
+    );
+    if values.choice::<Role>("role") == Role::Speaker {
+        add_error(
+            &mut errors,
+            "presentation",
+            hours_error(values.get("presentation")),
+        );
+    }
+
+    errors
+}
+
+// Options of a select, plus the record's current value when it is not one of
+// them, so the select shows it instead of silently picking the first option
+fn options_with_current<T: Choice>(
+    choices: &[T],
+    current: Option<T>,
+) -> Vec<(AttrValue, AttrValue)> {
+    let mut options = choice_options(choices);
+    if let Some(current) = current.filter(|current| !choices.contains(current)) {
+        options.insert(0, (current.key().into(), current.label().into()));
+    }
+    options
+}
+
 #[function_component(AdminUpdate)]
 pub fn admin_update(props: &AdminUpdateProps) -> Html {
     let navigator = use_navigator().unwrap();
@@ -20,173 +98,126 @@ pub fn admin_update(props: &AdminUpdateProps) -> Html {
 
     // Form state
     let user = use_state(|| None::<User>);
-    let email = use_state(|| String::new());
-    let full_name = use_state(|| String::new());
-    let identification = use_state(|| String::new());
-    let password = use_state(|| String::new());
-    let role = use_state(|| String::new());
-    let presentation = use_state(|| String::new());
-    let attendance = use_state(|| String::new());
-    let message = use_state(|| String::new());
+    let form = use_form(FormValues::default, validate_update);
+    let message = use_state(String::new);
     let loading = use_state(|| true);
+    let cache = use_user_cache();
+    let i18n = use_i18n();
+    let t = i18n.t();
+
+    // Read by the background refresh, which outlives this render's handles
+    let dirty = use_mut_ref(|| false);
+    *dirty.borrow_mut() = form.is_form_dirty();
 
-    // Fetch user data on mount
+    let fill_form = {
+        let user = user.clone();
+        let form = form.clone();
+        Callback::from(move |user_data: User| {
+            form.reset(user_values(&user_data));
+            user.set(Some(user_data));
+        })
+    };
+
+    // Show the cached copy right away, then refresh it from the server
     {
-        let user_id = user_id.clone();
         let user = user.clone();
-        let email = email.clone();
-        let full_name = full_name.clone();
-        let identification = identification.clone();
-        let role = role.clone();
-        let presentation = presentation.clone();
-        let attendance = attendance.clone();
+        let cache = cache.clone();
         let message = message.clone();
         let loading = loading.clone();
 
         use_effect_with_deps(
-            move |_| {
+            move |user_id: &String| {
+                let user_id = user_id.clone();
+                let cached = cache.user(&user_id);
+                if let Some(cached) = cached.clone() {
+                    fill_form.emit(cached);
+                    loading.set(false);
+                }
+
                 spawn_local(async move {
                     match ApiService::admin_get_user(&user_id).await {
                         Ok(user_data) => {
-                            // Populate form with user data
-                            email.set(user_data.email.clone());
-                            full_name.set(user_data.full_name.clone());
-                            identification.set(user_data.identification.clone());
-
-                            // Handle role
-                            match &user_data.role {
-                                crate::types::UserRole::Simple(r) => {
-                                    role.set(r.clone());
-                                    presentation.set(String::new());
-                                }
-                                crate::types::UserRole::Speaker { speaker } => {
-                                    role.set("speaker".to_string());
-                                    presentation.set(speaker.presentation.clone());
+                            if cached.as_ref() != Some(&user_data) {
+                                // Keep edits already started instead of
+                                // wiping them with the server copy
+                                if *dirty.borrow() {
+                                    user.set(Some(user_data.clone()));
+                                    message.set(t.admin_update.changed_on_server.to_string());
+                                } else {
+                                    fill_form.emit(user_data.clone());
                                 }
                             }
-
-                            attendance.set(user_data.attendance.clone());
-                            user.set(Some(user_data));
-                            loading.set(false);
+                            cache.store_user(user_data);
+                        }
+                        Err(ApiError::NotFound) => {
+                            cache.remove_user(&user_id);
+                            message.set(t.admin_update.not_found.to_string());
                         }
                         Err(error) => {
-                            message.set(format!("Error loading user: {}", error));
-                            loading.set(false);
+                            message.set((t.admin_update.load_error)(&error.to_string()));
                         }
                     }
+                    loading.set(false);
                 });
                 || ()
             },
-            (),
+            user_id.clone(),
         );
     }
 
-    let on_email_change = {
-        let email = email.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            email.set(input.value());
-        })
-    };
-
-    let on_full_name_change = {
-        let full_name = full_name.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            full_name.set(input.value());
-        })
-    };
-
-    let on_identification_change = {
-        let identification = identification.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            identification.set(input.value());
-        })
-    };
-
-    let on_password_change = {
-        let password = password.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            password.set(input.value());
-        })
-    };
-
-    let on_role_change = {
-        let role = role.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            role.set(select.value());
-        })
-    };
-
-    let on_presentation_change = {
-        let presentation = presentation.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            presentation.set(input.value());
-        })
-    };
-
-    let on_attendance_change = {
-        let attendance = attendance.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            attendance.set(select.value());
-        })
-    };
-
     let on_submit = {
         let user_id = user_id.clone();
-        let email = email.clone();
-        let full_name = full_name.clone();
-        let identification = identification.clone();
-        let password = password.clone();
-        let role = role.clone();
-        let presentation = presentation.clone();
-        let attendance = attendance.clone();
+        let user = user.clone();
+        let cache = cache.clone();
+        let form = form.clone();
         let message = message.clone();
         let navigator = navigator.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let user_id = user_id.clone();
-            let email_val = (*email).clone();
-            let full_name_val = (*full_name).clone();
-            let identification_val = (*identification).clone();
-            let password_val = (*password).clone();
-            let role_val = (*role).clone();
-            let presentation_val = (*presentation).to_string();
-            let attendance_val = (*attendance).clone();
+            let Some(values) = form.submit() else {
+                message.set(String::new());
+                return;
+            };
+            let previous = (*user).clone();
+            let cache = cache.clone();
             let message = message.clone();
             let navigator = navigator.clone();
 
-            // Validate password if provided
-            if !password_val.is_empty() && !validate_password(&password_val) {
-                message.set("Contraseña no válida".to_string());
-                return;
-            }
-
+            let role = values.choice("role");
             let data = AdminUpdateUserRequest {
-                id: user_id,
-                email: email_val,
-                full_name: full_name_val,
-                identification: identification_val,
-                password: password_val,
-                role: role_val,
-                presentation: presentation_val,
-                attendance: attendance_val,
+                id: user_id.clone(),
+                email: values.get("email").to_string(),
+                full_name: values.get("full_name").to_string(),
+                identification: values.get("identification").to_string(),
+                password: values.get("password").to_string(),
+                role,
+                presentation: (role == Role::Speaker).then(|| values.hours("presentation")),
+                attendance: values.choice("attendance"),
+                cert_generated: None,
             };
 
+            // Update the cached record up front and roll back on failure
+            if let Some(previous) = &previous {
+                cache.store_user(previous.with_update(&data));
+            }
+
             spawn_local(async move {
-                match ApiService::admin_update_user(data).await {
+                let result = ApiService::admin_update_user(data).await;
+                if let (Err(_), Some(previous)) = (&result, previous) {
+                    cache.store_user(previous);
+                }
+
+                match result {
                     Ok(_) => {
                         navigator.push(&Route::AdminPanel);
                     }
+                    Err(ApiError::Conflict(_)) => {
+                        message.set(t.admin_update.duplicate.to_string());
+                    }
                     Err(error) => {
-                        message.set(error);
+                        message.set(error.to_string());
                     }
                 }
             });
@@ -205,7 +236,7 @@ pub fn admin_update(props: &AdminUpdateProps) -> Html {
             <div class="container">
                 <div class="card">
                     <div class="spinner"></div>
-                    <p>{"Cargando datos del usuario..."}</p>
+                    <p>{t.admin_update.loading}</p>
                 </div>
             </div>
         }
@@ -213,100 +244,69 @@ pub fn admin_update(props: &AdminUpdateProps) -> Html {
         html! {
             <div class="container">
                 <div class="admin-header">
-                    <h1>{"Editar Usuario"}</h1>
+                    <h1>{t.admin_update.title}</h1>
                 </div>
 
                 <div class="card">
-                    <form onsubmit={on_submit}>
-                        <div class="form-group">
-                            <label for="email">{"Correo electrónico:"}</label>
-                            <input
-                                type="email"
-                                id="email"
-                                class="form-input"
-                                required={true}
-                                value={(*email).clone()}
-                                onchange={on_email_change}
-                            />
-                        </div>
-
-                        <div class="form-group">
-                            <label for="full-name">{"Nombre completo:"}</label>
-                            <input
-                                type="text"
-                                id="full-name"
-                                class="form-input"
-                                required={true}
-                                value={(*full_name).clone()}
-                                onchange={on_full_name_change}
-                            />
-                        </div>
-
-                        <div class="form-group">
-                            <label for="identification">{"Identificación:"}</label>
-                            <input
-                                type="text"
-                                id="identification"
-                                class="form-input"
-                                required={true}
-                                value={(*identification).clone()}
-                                onchange={on_identification_change}
-                            />
-                        </div>
-
-                        <div class="form-group">
-                            <label for="password">{"Nueva contraseña (dejar vacío para no cambiar):"}</label>
-                            <input
-                                type="password"
-                                id="password"
-                                class="form-input"
-                                value={(*password).clone()}
-                                onchange={on_password_change}
-                            />
-                        </div>
-
-                        <div class="form-group">
-                            <label for="role">{"Rol:"}</label>
-                            <select
-                                id="role"
-                                value={(*role).clone()}
-                                onchange={on_role_change}
-                            >
-                                <option value="attendee">{"Asistente"}</option>
-                                <option value="speaker">{"Ponente"}</option>
-                                <option value="staff">{"Organizador"}</option>
-                                <option value="webmaster">{"Administrador"}</option>
-                            </select>
-                        </div>
-
-                        {if *role == "speaker" {
+                    <form novalidate={true} onsubmit={on_submit}>
+                        <TextField
+                            form={form.clone()}
+                            name="email"
+                            id="email"
+                            label={t.fields.email}
+                            input_type="email"
+                            required={true}
+                        />
+                        <TextField
+                            form={form.clone()}
+                            name="full_name"
+                            id="full-name"
+                            label={t.fields.full_name}
+                            required={true}
+                        />
+                        <TextField
+                            form={form.clone()}
+                            name="identification"
+                            id="identification"
+                            label={t.admin_update.identification}
+                            required={true}
+                        />
+                        <PasswordField
+                            form={form.clone()}
+                            name="password"
+                            id="password"
+                            label={t.admin_update.new_password}
+                        />
+
+                        <SelectField
+                            form={form.clone()}
+                            name="role"
+                            id="role"
+                            label={t.fields.role}
+                            options={options_with_current(Role::ALL, user.as_ref().map(User::role))}
+                        />
+
+                        {if form.values().choice::<Role>("role") == Role::Speaker {
                             html! {
-                                <div class="form-group">
-                                    <label for="presentation">{"Horas de ponencia:"}</label>
-                                    <input
-                                        type="number"
-                                        id="presentation"
-                                        class="form-input"
-                                        value={presentation.to_string()}
-                                        onchange={on_presentation_change}
-                                    />
-                                </div>
+                                <TextField
+                                    form={form.clone()}
+                                    name="presentation"
+                                    id="presentation"
+                                    label={t.admin_update.hours}
+                                    inputmode="decimal"
+                                />
                             }
                         } else {
                             html! {}
                         }}
 
-                        <div class="form-group">
-                            <label for="attendance">{"Tipo de asistencia:"}</label>
-                            <select
-                                id="attendance"
-                                value={(*attendance).clone()}
-                                onchange={on_attendance_change}
-                            >
-                                <option value="remote">{"Remota"}</option>
-                                <option value="presential">{"Presencial"}</option>
-                            </select>
-                        </div>
+                        <SelectField
+                            form={form.clone()}
+                            name="attendance"
+                            id="attendance"
+                            label={t.fields.attendance}
+                            options={options_with_current(Attendance::ALL, user.as_ref().map(|user| user.attendance))}
+                        />
 
                         {if !message.is_empty() {
                             html! {
@@ -317,15 +317,19 @@ pub fn admin_update(props: &AdminUpdateProps) -> Html {
                         }}
 
                         <div class="admin-actions">
-                            <button type="submit" class="btn btn-success">
-                                {"Guardar cambios"}
+                            <button
+                                type="submit"
+                                class="btn btn-success"
+                                disabled={!form.is_form_dirty()}
+                            >
+                                {t.admin_update.save}
                             </button>
                             <button
                                 type="button"
                                 class="btn btn-outline"
                                 onclick={on_cancel}
                             >
-                                {"Cancelar"}
+                                {t.admin_update.cancel}
                             </button>
                         </div>
                     </form>
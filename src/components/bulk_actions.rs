@@ -0,0 +1,217 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::contexts::use_i18n;
+use crate::i18n;
+use crate::services::api::ApiService;
+use crate::types::{AdminUpdateUserRequest, Attendance, CertificateStatus, Choice, Role, User};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BulkAction {
+    Delete,
+    SetAttendance(Attendance),
+    SetRole(Role),
+    ResetCertificates,
+}
+
+impl BulkAction {
+    const ALL: &'static [(&'static str, BulkAction)] = &[
+        ("delete", BulkAction::Delete),
+        (
+            "attendance-remote",
+            BulkAction::SetAttendance(Attendance::Remote),
+        ),
+        (
+            "attendance-presential",
+            BulkAction::SetAttendance(Attendance::Presential),
+        ),
+        ("role-attendee", BulkAction::SetRole(Role::Attendee)),
+        ("role-speaker", BulkAction::SetRole(Role::Speaker)),
+        ("role-staff", BulkAction::SetRole(Role::Staff)),
+        ("certs-reset", BulkAction::ResetCertificates),
+    ];
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|(action_key, _)| *action_key == key)
+            .map(|(_, action)| *action)
+    }
+
+    // e.g. "Rol: Ponente", in the current language
+    fn label(&self) -> String {
+        let t = i18n::messages();
+        match self {
+            BulkAction::Delete => t.bulk.delete.to_string(),
+            BulkAction::SetAttendance(attendance) => {
+                format!("{}: {}", t.columns.attendance, attendance.label())
+            }
+            BulkAction::SetRole(role) => format!("{}: {}", t.columns.role, role.label()),
+            BulkAction::ResetCertificates => t.bulk.reset_certificates.to_string(),
+        }
+    }
+
+    async fn apply(&self, user: &User) -> Result<(), String> {
+        // Updates resend the whole record, which would overwrite a value this
+        // version does not understand
+        let unknown = user.role() == Role::Unknown || user.attendance == Attendance::Unknown;
+        if unknown && *self != BulkAction::Delete {
+            return Err(i18n::messages().bulk.unknown_values.to_string());
+        }
+
+        let result = match self {
+            BulkAction::Delete => ApiService::delete_user(&user.id).await,
+            BulkAction::SetAttendance(attendance) => {
+                let mut request = AdminUpdateUserRequest::from_user(user);
+                request.attendance = *attendance;
+                ApiService::admin_update_user(request).await
+            }
+            BulkAction::SetRole(role) => {
+                let mut request = AdminUpdateUserRequest::from_user(user);
+                request.role = *role;
+                // Speakers always carry hours: new ones start at 0 and
+                // existing ones keep theirs
+                request.presentation =
+                    (*role == Role::Speaker).then(|| user.speaker_hours().unwrap_or_default());
+                ApiService::admin_update_user(request).await
+            }
+            BulkAction::ResetCertificates => {
+                let mut request = AdminUpdateUserRequest::from_user(user);
+                request.cert_generated = Some(CertificateStatus::default());
+                ApiService::admin_update_user(request).await
+            }
+        };
+        result.map(|_| ()).map_err(|error| error.to_string())
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BulkActionsProps {
+    pub selected: Vec<User>,
+    // Called once every selected user has been processed
+    pub on_done: Callback<()>,
+}
+
+#[function_component(BulkActions)]
+pub fn bulk_actions(props: &BulkActionsProps) -> Html {
+    let action = use_state(|| None::<BulkAction>);
+    let running = use_state(|| false);
+    let results = use_state(Vec::<(String, Result<(), String>)>::new);
+    let i18n = use_i18n();
+    let t = &i18n.t().bulk;
+
+    let on_action_change = {
+        let action = action.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            action.set(BulkAction::from_key(&select.value()));
+        })
+    };
+
+    let on_apply = {
+        let action = action.clone();
+        let running = running.clone();
+        let results = results.clone();
+        let selected = props.selected.clone();
+        let on_done = props.on_done.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(bulk_action) = *action else {
+                return;
+            };
+
+            // One confirmation for the whole batch
+            if !gloo_utils::window()
+                .confirm_with_message(&(t.confirm)(&bulk_action.label(), selected.len()))
+                .unwrap_or(false)
+            {
+                return;
+            }
+
+            let running = running.clone();
+            let results = results.clone();
+            let selected = selected.clone();
+            let on_done = on_done.clone();
+
+            running.set(true);
+            spawn_local(async move {
+                let mut summary = Vec::new();
+                for user in &selected {
+                    let result = bulk_action.apply(user).await;
+                    summary.push((user.full_name.clone(), result));
+                    results.set(summary.clone());
+                }
+                running.set(false);
+                on_done.emit(());
+            });
+        })
+    };
+
+    let on_close_summary = {
+        let results = results.clone();
+        Callback::from(move |_: MouseEvent| results.set(Vec::new()))
+    };
+
+    let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+
+    html! {
+        <section id="bulk-section">
+            {if props.selected.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <div class="admin-actions">
+                        <span>{(t.selected)(props.selected.len())}</span>
+                        <select id="bulk-action-select" aria-label={t.action_label} onchange={on_action_change} disabled={*running}>
+                            <option value="" selected={action.is_none()}>{t.choose}</option>
+                            {for BulkAction::ALL.iter().map(|(key, action)| html! {
+                                <option value={*key}>{action.label()}</option>
+                            })}
+                        </select>
+                        <button
+                            id="bulk-apply-btn"
+                            class={if *action == Some(BulkAction::Delete) { "btn btn-danger" } else { "btn" }}
+                            onclick={on_apply}
+                            disabled={action.is_none() || *running}
+                        >
+                            {if *running {
+                                html! { <span class="spinner" style="width: 1rem; height: 1rem;"></span> }
+                            } else {
+                                html! { {t.apply} }
+                            }}
+                        </button>
+                    </div>
+                }
+            }}
+
+            {if results.is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <div id="bulk-summary" role="status">
+                        <p>
+                            {(t.summary)(results.len() - failed, failed)}
+                        </p>
+                        <ul>
+                            {for results.iter().map(|(name, result)| match result {
+                                Ok(()) => html! {
+                                    <li><span class="badge badge-success">{t.ok}</span>{" "}{name}</li>
+                                },
+                                Err(error) => html! {
+                                    <li><span class="badge badge-danger">{t.error}</span>{format!(" {}: {}", name, error)}</li>
+                                },
+                            })}
+                        </ul>
+                        {if *running {
+                            html! {}
+                        } else {
+                            html! {
+                                <button class="btn btn-sm btn-outline" onclick={on_close_summary}>{t.close}</button>
+                            }
+                        }}
+                    </div>
+                }
+            }}
+        </section>
+    }
+}
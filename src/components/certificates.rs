@@ -1,73 +1,287 @@
+use std::collections::HashMap;
+
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
+use crate::contexts::use_i18n;
 use crate::services::api::ApiService;
+use crate::services::error::ApiError;
+use crate::types::{CertificateFile, CertificateOrientation, User};
+use crate::utils::{download_file, object_url, revoke_object_url};
+
+#[derive(Clone, PartialEq)]
+struct Preview {
+    url: String,
+    is_image: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CertificateState {
+    NotEligible,
+    Available,
+    Generated,
+}
 
 #[function_component(Certificates)]
 pub fn certificates() -> Html {
-    let message = use_state(|| String::new());
+    let user = use_state(|| None::<User>);
+    let loading = use_state(|| true);
+    let files = use_state(HashMap::<CertificateOrientation, CertificateFile>::new);
+    let pending = use_state(|| None::<CertificateOrientation>);
+    let preview = use_state(|| None::<Preview>);
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = &i18n.t().certificates;
 
-    let on_generate_horizontal = {
+    // Certificate status, eligibility and the name used for file names
+    {
+        let user = user.clone();
+        let loading = loading.clone();
         let message = message.clone();
-        Callback::from(move |_: MouseEvent| {
-            let message = message.clone();
-
-            spawn_local(async move {
-                match ApiService::generate_horizontal_cert().await {
-                    Ok(response) => {
-                        log::info!("Success: {}", response);
-                        message.set("Certificado horizontal generado exitosamente".to_string());
+        use_effect_with_deps(
+            move |_| {
+                spawn_local(async move {
+                    match ApiService::get_current_user().await {
+                        Ok(user_data) => user.set(Some(user_data)),
+                        Err(error) => message.set((t.load_error)(&error.to_string())),
                     }
-                    Err(error) => {
-                        message.set(error);
+                    loading.set(false);
+                });
+                || ()
+            },
+            (),
+        );
+    }
+
+    // Release the preview object URL when it is replaced or the page unmounts
+    {
+        let url = preview.as_ref().map(|preview| preview.url.clone());
+        use_effect_with_deps(
+            move |_| {
+                move || {
+                    if let Some(url) = url {
+                        revoke_object_url(&url);
                     }
                 }
-            });
-        })
-    };
+            },
+            preview.clone(),
+        );
+    }
 
-    let on_generate_vertical = {
+    // (orientation, show inline preview instead of downloading)
+    let on_certificate = {
+        let user = user.clone();
+        let files = files.clone();
+        let pending = pending.clone();
+        let preview = preview.clone();
         let message = message.clone();
-        Callback::from(move |_: MouseEvent| {
-            let message = message.clone();
-
-            spawn_local(async move {
-                match ApiService::generate_vertical_cert().await {
-                    Ok(response) => {
-                        log::info!("Success: {}", response);
-                        message.set("Certificado vertical generado exitosamente".to_string());
-                    }
-                    Err(error) => {
-                        message.set(error);
-                    }
+
+        Callback::from(
+            move |(orientation, show_preview): (CertificateOrientation, bool)| {
+                let Some(current) = (*user).clone() else {
+                    return;
+                };
+                if pending.is_some() {
+                    return;
                 }
-            });
-        })
+
+                let user = user.clone();
+                let files = files.clone();
+                let pending = pending.clone();
+                let preview = preview.clone();
+                let message = message.clone();
+
+                spawn_local(async move {
+                    let file = match files.get(&orientation) {
+                        Some(file) => file.clone(),
+                        None => {
+                            pending.set(Some(orientation));
+                            let result = match orientation {
+                                CertificateOrientation::Horizontal => {
+                                    ApiService::generate_horizontal_cert(&current.full_name).await
+                                }
+                                CertificateOrientation::Vertical => {
+                                    ApiService::generate_vertical_cert(&current.full_name).await
+                                }
+                            };
+                            pending.set(None);
+
+                            match result {
+                                Ok(file) => {
+                                    let mut cached = (*files).clone();
+                                    cached.insert(orientation, file.clone());
+                                    files.set(cached);
+
+                                    let mut updated = current;
+                                    updated.cert_generated.set_generated(orientation);
+                                    user.set(Some(updated));
+                                    file
+                                }
+                                Err(ApiError::Forbidden) => {
+                                    let mut updated = current;
+                                    updated.attendance_confirmed = Some(false);
+                                    user.set(Some(updated));
+                                    message.set(t.not_eligible.to_string());
+                                    return;
+                                }
+                                Err(error) => {
+                                    message.set(error.to_string());
+                                    return;
+                                }
+                            }
+                        }
+                    };
+
+                    let result = if show_preview {
+                        object_url(&file.bytes, &file.content_type).map(|url| {
+                            preview.set(Some(Preview {
+                                url,
+                                is_image: file.is_image(),
+                            }));
+                        })
+                    } else {
+                        download_file(&file.bytes, &file.content_type, &file.filename)
+                    };
+
+                    match result {
+                        Ok(()) => message.set(String::new()),
+                        Err(error) => {
+                            log::error!("Could not open certificate: {:?}", error);
+                            message.set(t.open_error.to_string());
+                        }
+                    }
+                });
+            },
+        )
+    };
+
+    let on_close_preview = {
+        let preview = preview.clone();
+        Callback::from(move |_: MouseEvent| preview.set(None))
+    };
+
+    let certificate_state = |orientation: CertificateOrientation| match user.as_ref() {
+        Some(user) if user.cert_generated.is_generated(orientation) => CertificateState::Generated,
+        Some(user) if user.can_generate_certificates() => CertificateState::Available,
+        _ => CertificateState::NotEligible,
+    };
+
+    let certificate_section = |orientation: CertificateOrientation,
+                               id: &'static str,
+                               image: &'static str| {
+        let label = match orientation {
+            CertificateOrientation::Horizontal => t.horizontal,
+            CertificateOrientation::Vertical => t.vertical,
+        };
+        let state = certificate_state(orientation);
+        let is_pending = *pending == Some(orientation);
+        let disabled = state == CertificateState::NotEligible || pending.is_some();
+
+        let on_download = {
+            let on_certificate = on_certificate.clone();
+            Callback::from(move |_: MouseEvent| on_certificate.emit((orientation, false)))
+        };
+        let on_preview = {
+            let on_certificate = on_certificate.clone();
+            Callback::from(move |_: MouseEvent| on_certificate.emit((orientation, true)))
+        };
+
+        let (status, action) = match state {
+            CertificateState::NotEligible => (t.unavailable, t.download),
+            CertificateState::Available => (t.available, t.generate),
+            CertificateState::Generated => (t.generated, t.download_again),
+        };
+
+        html! {
+            <section>
+                <figure>
+                    <img src={image} alt={(t.example_alt)(label)} />
+                </figure>
+                <p>{label}</p>
+                <p class="cert-status">{status}</p>
+                <button id={id} class="btn" onclick={on_download} {disabled}>
+                    {if is_pending {
+                        html! { <span class="spinner" style="width: 1rem; height: 1rem;"></span> }
+                    } else {
+                        html! { {action} }
+                    }}
+                </button>
+                <button class="btn btn-outline" onclick={on_preview} {disabled}>
+                    {t.preview}
+                </button>
+            </section>
+        }
     };
 
+    if *loading {
+        return html! {
+            <div class="container">
+                <div class="spinner"></div>
+                <p>{t.loading}</p>
+            </div>
+        };
+    }
+
     html! {
         <>
-            <h1>{"Descargar certificados"}</h1>
+            <h1>{t.title}</h1>
             <article>
-                <section>
-                    <figure>
-                        <img src="/img/horiz_cert_example.png" alt="Horizontal certificate example" />
-                    </figure>
-                    <p>{"Horizontal certificate"}</p>
-                    <button id="horiz-cert-btn" class="btn" onclick={on_generate_horizontal}>
-                        {"Generate"}
-                    </button>
-                </section>
-
-                <section>
-                    <figure>
-                        <img src="/img/vert_cert_example.png" alt="Vertical certificate example" />
-                    </figure>
-                    <p>{"Vertical certificate"}</p>
-                    <button id="vert-cert-btn" class="btn" onclick={on_generate_vertical}>
-                        {"Generate"}
-                    </button>
-                </section>
+                {if user
+                    .as_ref()
+                    .is_some_and(|user| !user.can_generate_certificates())
+                {
+                    html! {
+                        <p class="notice">
+                            {t.pending_notice}
+                        </p>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                {if let Some(hours) = user.as_ref().and_then(User::speaker_hours) {
+                    html! {
+                        <p id="cert-hours">
+                            <strong>{t.certified_hours}</strong>{hours}
+                        </p>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                {certificate_section(
+                    CertificateOrientation::Horizontal,
+                    "horiz-cert-btn",
+                    "/img/horiz_cert_example.png",
+                )}
+                {certificate_section(
+                    CertificateOrientation::Vertical,
+                    "vert-cert-btn",
+                    "/img/vert_cert_example.png",
+                )}
+
+                {if let Some(preview) = preview.as_ref() {
+                    html! {
+                        <section id="cert-preview">
+                            {if preview.is_image {
+                                html! { <img src={preview.url.clone()} alt={t.preview_title} /> }
+                            } else {
+                                html! {
+                                    <iframe
+                                        src={preview.url.clone()}
+                                        title={t.preview_title}
+                                        style="width: 100%; height: 80vh;"
+                                    />
+                                }
+                            }}
+                            <button class="btn btn-outline" onclick={on_close_preview}>
+                                {t.close_preview}
+                            </button>
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
 
                 <div>
                     <span id="message-span">{(*message).clone()}</span>
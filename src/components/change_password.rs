@@ -0,0 +1,129 @@
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::components::PasswordField;
+use crate::contexts::use_i18n;
+use crate::form::{
+    add_error, password_error, repeated_password_error, required_error, use_form, FormErrors,
+    FormValues,
+};
+use crate::i18n;
+use crate::services::api::ApiService;
+use crate::services::error::ApiError;
+use crate::types::ChangePasswordRequest;
+
+fn empty_values() -> FormValues {
+    FormValues::new(&[
+        ("current_password", ""),
+        ("new_password", ""),
+        ("repeated_password", ""),
+    ])
+}
+
+fn validate_change_password(values: &FormValues) -> FormErrors {
+    let mut errors = FormErrors::new();
+    let new_password = values.get("new_password");
+
+    add_error(
+        &mut errors,
+        "current_password",
+        required_error(values.get("current_password")),
+    );
+    add_error(
+        &mut errors,
+        "new_password",
+        password_error(new_password).or_else(|| {
+            (new_password == values.get("current_password"))
+                .then(|| i18n::messages().change_password.must_differ.to_string())
+        }),
+    );
+    add_error(
+        &mut errors,
+        "repeated_password",
+        repeated_password_error(new_password, values.get("repeated_password")),
+    );
+
+    errors
+}
+
+#[function_component(ChangePassword)]
+pub fn change_password() -> Html {
+    let form = use_form(empty_values, validate_change_password);
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = &i18n.t().change_password;
+
+    let on_submit = {
+        let form = form.clone();
+        let message = message.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let Some(values) = form.submit() else {
+                message.set(String::new());
+                return;
+            };
+            let form = form.clone();
+            let message = message.clone();
+
+            let data = ChangePasswordRequest {
+                current_password: values.get("current_password").to_string(),
+                new_password: values.get("new_password").to_string(),
+            };
+
+            spawn_local(async move {
+                match ApiService::change_password(data).await {
+                    Ok(response) => {
+                        log::info!("Success: {}", response);
+                        form.reset(empty_values());
+                        message.set(t.success.to_string());
+                    }
+                    Err(ApiError::Unauthorized | ApiError::Forbidden) => {
+                        message.set(t.wrong_current.to_string());
+                    }
+                    Err(error) => {
+                        message.set(error.to_string());
+                    }
+                }
+            });
+        })
+    };
+
+    html! {
+        <section>
+            <h2>{t.title}</h2>
+            <form id="password-form" novalidate={true} onsubmit={on_submit}>
+                <PasswordField
+                    form={form.clone()}
+                    name="current_password"
+                    id="current-password-input"
+                    label={t.current}
+                    required={true}
+                />
+                <PasswordField
+                    form={form.clone()}
+                    name="new_password"
+                    id="new-password-input"
+                    label={t.new}
+                    required={true}
+                    minlength="8"
+                />
+                <PasswordField
+                    form={form.clone()}
+                    name="repeated_password"
+                    id="repeat-new-password-input"
+                    label={t.repeat}
+                    required={true}
+                    minlength="8"
+                />
+
+                <div>
+                    <span id="password-message-span">{(*message).clone()}</span>
+                </div>
+
+                <button type="submit" id="change-password-btn">{t.title}</button>
+            </form>
+        </section>
+    }
+}
@@ -0,0 +1,32 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::contexts::use_i18n;
+use crate::routes::Route;
+
+#[function_component(Forbidden)]
+pub fn forbidden() -> Html {
+    let navigator = use_navigator().unwrap();
+    let i18n = use_i18n();
+    let t = &i18n.t().errors;
+
+    let on_home_click = {
+        let navigator = navigator.clone();
+        Callback::from(move |_: MouseEvent| {
+            navigator.push(&Route::Home);
+        })
+    };
+
+    html! {
+        <div class="container text-center">
+            <div class="card" style="max-width: 600px; margin: 0 auto;">
+                <h1 class="glow-text" style="font-size: 6rem; margin-bottom: 0;">{"403"}</h1>
+                <h2>{t.forbidden_title}</h2>
+                <p>{t.forbidden_text}</p>
+                <button class="btn mt-3" onclick={on_home_click}>
+                    {t.back_home}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,196 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::contexts::use_i18n;
+use crate::form::UseForm;
+use crate::types::Choice;
+
+// (value, label) options of a `SelectField` for typed choices
+pub fn choice_options<T: Choice>(choices: &[T]) -> Vec<(AttrValue, AttrValue)> {
+    choices
+        .iter()
+        .map(|choice| (choice.key().into(), choice.label().into()))
+        .collect()
+}
+
+// Values follow every keystroke so errors update live once shown
+fn on_input(form: &UseForm, name: &'static str) -> Callback<InputEvent> {
+    let form = form.clone();
+    Callback::from(move |e: InputEvent| {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        form.set(name, input.value());
+    })
+}
+
+fn on_blur(form: &UseForm, name: &'static str) -> Callback<FocusEvent> {
+    let form = form.clone();
+    Callback::from(move |_: FocusEvent| form.touch(name))
+}
+
+fn error_id(id: &AttrValue) -> AttrValue {
+    AttrValue::from(format!("{}-error", id))
+}
+
+// Always rendered so screen readers pick up the live region before the
+// first message appears
+fn field_error(id: &AttrValue, error: Option<String>) -> Html {
+    html! {
+        <span id={error_id(id)} class="field-error" aria-live="polite">
+            {error.unwrap_or_default()}
+        </span>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TextFieldProps {
+    pub form: UseForm,
+    pub name: &'static str,
+    pub id: AttrValue,
+    pub label: AttrValue,
+    // "text", "email", "number"...
+    #[prop_or(AttrValue::Static("text"))]
+    pub input_type: AttrValue,
+    #[prop_or_default]
+    pub required: bool,
+    #[prop_or_default]
+    pub disabled: bool,
+    #[prop_or_default]
+    pub minlength: Option<AttrValue>,
+    // Keyboard hint for mobile, e.g. "decimal"
+    #[prop_or_default]
+    pub inputmode: Option<AttrValue>,
+}
+
+#[function_component(TextField)]
+pub fn text_field(props: &TextFieldProps) -> Html {
+    let form = &props.form;
+    let error = form.error(props.name);
+
+    html! {
+        <div class="form-group">
+            <label for={props.id.clone()}>{props.label.clone()}</label>
+            <input
+                type={props.input_type.clone()}
+                id={props.id.clone()}
+                class="form-input"
+                required={props.required}
+                disabled={props.disabled}
+                minlength={props.minlength.clone()}
+                inputmode={props.inputmode.clone()}
+                value={form.value(props.name)}
+                aria-invalid={error.is_some().to_string()}
+                aria-describedby={error_id(&props.id)}
+                oninput={on_input(form, props.name)}
+                onblur={on_blur(form, props.name)}
+            />
+            {field_error(&props.id, error)}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PasswordFieldProps {
+    pub form: UseForm,
+    pub name: &'static str,
+    pub id: AttrValue,
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub required: bool,
+    #[prop_or_default]
+    pub minlength: Option<AttrValue>,
+}
+
+// Password input with a toggle to reveal what was typed
+#[function_component(PasswordField)]
+pub fn password_field(props: &PasswordFieldProps) -> Html {
+    let form = &props.form;
+    let error = form.error(props.name);
+    let visible = use_state(|| false);
+    let i18n = use_i18n();
+    let t = &i18n.t().common;
+
+    let on_toggle = {
+        let visible = visible.clone();
+        Callback::from(move |_: MouseEvent| visible.set(!*visible))
+    };
+
+    html! {
+        <div class="form-group">
+            <label for={props.id.clone()}>{props.label.clone()}</label>
+            <div class="password-field">
+                <input
+                    type={if *visible { "text" } else { "password" }}
+                    id={props.id.clone()}
+                    class="form-input"
+                    required={props.required}
+                    minlength={props.minlength.clone()}
+                    value={form.value(props.name)}
+                    aria-invalid={error.is_some().to_string()}
+                    aria-describedby={error_id(&props.id)}
+                    oninput={on_input(form, props.name)}
+                    onblur={on_blur(form, props.name)}
+                />
+                <button
+                    type="button"
+                    class="btn btn-sm btn-outline"
+                    aria-controls={props.id.clone()}
+                    aria-pressed={visible.to_string()}
+                    onclick={on_toggle}
+                >
+                    {if *visible { t.hide } else { t.show }}
+                </button>
+            </div>
+            {field_error(&props.id, error)}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SelectFieldProps {
+    pub form: UseForm,
+    pub name: &'static str,
+    pub id: AttrValue,
+    pub label: AttrValue,
+    // (value, label) pairs
+    pub options: Vec<(AttrValue, AttrValue)>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[function_component(SelectField)]
+pub fn select_field(props: &SelectFieldProps) -> Html {
+    let form = &props.form;
+    let name = props.name;
+    let current = form.value(name);
+    let error = form.error(name);
+
+    let on_change = {
+        let form = form.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            form.set(name, select.value());
+            form.touch(name);
+        })
+    };
+
+    html! {
+        <div class="form-group">
+            <label for={props.id.clone()}>{props.label.clone()}</label>
+            <select
+                id={props.id.clone()}
+                value={current.clone()}
+                disabled={props.disabled}
+                aria-invalid={error.is_some().to_string()}
+                aria-describedby={error_id(&props.id)}
+                onchange={on_change}
+            >
+                {for props.options.iter().map(|(value, label)| html! {
+                    <option value={value.clone()} selected={*value == current}>
+                        {label.clone()}
+                    </option>
+                })}
+            </select>
+            {field_error(&props.id, error)}
+        </div>
+    }
+}
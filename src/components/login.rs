@@ -1,73 +1,84 @@
 use wasm_bindgen_futures::spawn_local;
-use web_sys::HtmlInputElement;
 use yew::prelude::*;
+use yew_router::history::{BrowserHistory, History};
 use yew_router::prelude::*;
 
-use crate::routes::Route;
+use crate::components::{PasswordField, TextField};
+use crate::contexts::{use_auth, use_i18n};
+use crate::form::{add_error, email_error, password_error, use_form, FormErrors, FormValues};
+use crate::routes::{LoginQuery, Route};
 use crate::services::api::ApiService;
-use crate::services::auth::AuthService;
+use crate::services::error::ApiError;
 use crate::types::LoginRequest;
-use crate::utils::validate_password;
+
+fn validate_login(values: &FormValues) -> FormErrors {
+    let mut errors = FormErrors::new();
+    add_error(&mut errors, "email", email_error(values.get("email")));
+    add_error(
+        &mut errors,
+        "password",
+        password_error(values.get("password")),
+    );
+    errors
+}
 
 #[function_component(Login)]
 pub fn login() -> Html {
     let navigator = use_navigator().unwrap();
-    let email = use_state(|| String::new());
-    let password = use_state(|| String::new());
-    let message = use_state(|| String::new());
-
-    let on_email_change = {
-        let email = email.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            email.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_password_change = {
-        let password = password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            password.set(input.value());
-            message.set(String::new());
-        })
-    };
+    let auth = use_auth();
+    let i18n = use_i18n();
+    let t = i18n.t();
+    let query = use_location()
+        .and_then(|location| location.query::<LoginQuery>().ok())
+        .unwrap_or_default();
+    let form = use_form(
+        || FormValues::new(&[("email", ""), ("password", "")]),
+        validate_login,
+    );
+    let message = use_state(String::new);
 
     let on_submit = {
-        let email = email.clone();
-        let password = password.clone();
+        let form = form.clone();
         let message = message.clone();
         let navigator = navigator.clone();
+        let redirect = query.redirect_path().map(str::to_string);
+        let auth = auth.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let email_val = (*email).clone();
-            let password_val = (*password).clone();
+            let Some(values) = form.submit() else {
+                message.set(String::new());
+                return;
+            };
             let message = message.clone();
             let navigator = navigator.clone();
-
-            if !validate_password(&password_val) {
-                message.set("Contraseña no valida".to_string());
-                return;
-            }
+            let redirect = redirect.clone();
+            let auth = auth.clone();
 
             let data = LoginRequest {
-                email: email_val,
-                password: password_val,
+                email: values.get("email").to_string(),
+                password: values.get("password").to_string(),
             };
 
             spawn_local(async move {
                 match ApiService::login(data).await {
                     Ok(token) => {
-                        AuthService::set_token(token);
-                        navigator.push(&Route::Profile);
+                        if !auth.login(token) {
+                            message.set(t.login.invalid_session.to_string());
+                            return;
+                        }
+                        match redirect {
+                            // Keeps the query string of the page the user came from
+                            Some(path) => BrowserHistory::new().push(path),
+                            None => navigator.push(&Route::Profile),
+                        }
+                    }
+                    Err(ApiError::Unauthorized | ApiError::NotFound) => {
+                        message.set(t.login.wrong_credentials.to_string());
                     }
                     Err(error) => {
-                        message.set(error);
+                        message.set(error.to_string());
                     }
                 }
             });
@@ -76,38 +87,40 @@ pub fn login() -> Html {
 
     html! {
         <>
-            <h1>{"Ingreso"}</h1>
+            <h1>{t.login.title}</h1>
+            {if query.expired {
+                html! {
+                    <p class="notice" role="status">
+                        {t.login.expired}
+                    </p>
+                }
+            } else {
+                html! {}
+            }}
             <article>
-                <form onsubmit={on_submit}>
-                    <div class="form-group">
-                        <label for="email-input">{"Correo electrónico:"}</label>
-                        <input
-                            type="email"
-                            id="email-input"
-                            class="form-input"
-                            required={true}
-                            minlength="5"
-                            value={(*email).clone()}
-                            onchange={on_email_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="password-input">{"Contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*password).clone()}
-                            onchange={on_password_change}
-                        />
-                    </div>
+                <form novalidate={true} onsubmit={on_submit}>
+                    <TextField
+                        form={form.clone()}
+                        name="email"
+                        id="email-input"
+                        label={t.fields.email}
+                        input_type="email"
+                        required={true}
+                        minlength="5"
+                    />
+                    <PasswordField
+                        form={form.clone()}
+                        name="password"
+                        id="password-input"
+                        label={t.fields.password}
+                        required={true}
+                        minlength="8"
+                    />
 
                     <div>
                         <span id="message-span">{(*message).clone()}</span>
                     </div>
-                    <button type="submit" id="login-btn">{"Ingresar"}</button>
+                    <button type="submit" id="login-btn">{t.login.submit}</button>
                 </form>
             </article>
         </>
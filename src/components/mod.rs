@@ -1,23 +1,37 @@
+pub mod admin_import;
 pub mod admin_panel;
 pub mod admin_register;
 pub mod admin_update;
+pub mod bulk_actions;
 pub mod certificates;
+pub mod change_password;
+pub mod forbidden;
+pub mod form_fields;
 pub mod home;
 pub mod login;
 pub mod navbar;
 pub mod not_found;
+pub mod pager;
 pub mod profile;
 pub mod protected_route;
 pub mod register;
+pub mod user_filters;
 
+pub use admin_import::AdminImport;
 pub use admin_panel::AdminPanel;
 pub use admin_register::AdminRegister;
 pub use admin_update::AdminUpdate;
+pub use bulk_actions::BulkActions;
 pub use certificates::Certificates;
+pub use change_password::ChangePassword;
+pub use forbidden::Forbidden;
+pub use form_fields::{choice_options, PasswordField, SelectField, TextField};
 pub use home::Home;
 pub use login::Login;
 pub use navbar::Navbar;
 pub use not_found::NotFound;
+pub use pager::Pager;
 pub use profile::Profile;
 pub use protected_route::ProtectedRoute;
 pub use register::Register;
+pub use user_filters::UserFilters;
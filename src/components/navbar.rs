@@ -1,24 +1,38 @@
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::contexts::{use_auth, use_i18n};
+use crate::i18n::Language;
 use crate::routes::Route;
-use crate::services::auth::AuthService;
+use crate::types::Choice;
 
 #[function_component(Navbar)]
 pub fn navbar() -> Html {
     let navigator = use_navigator().unwrap();
-    let is_authenticated = use_state(|| AuthService::is_authenticated());
+    let auth = use_auth();
+    let i18n = use_i18n();
+    let t = &i18n.t().navbar;
 
     let on_logout = {
         let navigator = navigator.clone();
-        let is_authenticated = is_authenticated.clone();
+        let auth = auth.clone();
         Callback::from(move |_: MouseEvent| {
-            AuthService::logout();
-            is_authenticated.set(false);
+            auth.logout();
             navigator.push(&Route::Home);
         })
     };
 
+    let on_language_change = {
+        let i18n = i18n.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(language) = Language::from_key(&select.value()) {
+                i18n.set_language(language);
+            }
+        })
+    };
+
     html! {
         <nav class="main-nav">
             <div class="nav-container">
@@ -29,17 +43,23 @@ pub fn navbar() -> Html {
                 </div>
 
                 <ul class="nav-links">
-                    <li><Link<Route> to={Route::Home} classes="nav-link">{"Inicio"}</Link<Route>></li>
+                    <li><Link<Route> to={Route::Home} classes="nav-link">{t.home}</Link<Route>></li>
 
-                    {if *is_authenticated {
+                    {if auth.is_authenticated() {
                         html! {
                             <>
-                                <li><Link<Route> to={Route::Profile} classes="nav-link">{"Perfil"}</Link<Route>></li>
-                                <li><Link<Route> to={Route::Certificates} classes="nav-link">{"Certificados"}</Link<Route>></li>
-                                <li><Link<Route> to={Route::AdminPanel} classes="nav-link">{"Admin"}</Link<Route>></li>
+                                <li><Link<Route> to={Route::Profile} classes="nav-link">{t.profile}</Link<Route>></li>
+                                <li><Link<Route> to={Route::Certificates} classes="nav-link">{t.certificates}</Link<Route>></li>
+                                {if auth.is_admin() {
+                                    html! {
+                                        <li><Link<Route> to={Route::AdminPanel} classes="nav-link">{t.admin}</Link<Route>></li>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
                                 <li>
                                     <button class="btn btn-sm btn-outline" onclick={on_logout}>
-                                        {"Cerrar Sesión"}
+                                        {t.logout}
                                     </button>
                                 </li>
                             </>
@@ -47,15 +67,29 @@ pub fn navbar() -> Html {
                     } else {
                         html! {
                             <>
-                                <li><Link<Route> to={Route::Register} classes="nav-link">{"Registro"}</Link<Route>></li>
+                                <li><Link<Route> to={Route::Register} classes="nav-link">{t.register}</Link<Route>></li>
                                 <li>
                                     <Link<Route> to={Route::Login} classes="btn btn-sm">
-                                        {"Ingresar"}
+                                        {t.login}
                                     </Link<Route>>
                                 </li>
                             </>
                         }
                     }}
+                    <li>
+                        <select
+                            id="language-select"
+                            class="language-select"
+                            aria-label={t.language}
+                            onchange={on_language_change}
+                        >
+                            {for Language::ALL.iter().map(|language| html! {
+                                <option value={language.key()} selected={*language == i18n.language()}>
+                                    {language.label()}
+                                </option>
+                            })}
+                        </select>
+                    </li>
                 </ul>
 
                 <div class="nav-toggle">
@@ -1,11 +1,14 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::contexts::use_i18n;
 use crate::routes::Route;
 
 #[function_component(NotFound)]
 pub fn not_found() -> Html {
     let navigator = use_navigator().unwrap();
+    let i18n = use_i18n();
+    let t = &i18n.t().errors;
 
     let on_home_click = {
         let navigator = navigator.clone();
@@ -18,10 +21,10 @@ pub fn not_found() -> Html {
         <div class="container text-center">
             <div class="card" style="max-width: 600px; margin: 0 auto;">
                 <h1 class="glow-text" style="font-size: 6rem; margin-bottom: 0;">{"404"}</h1>
-                <h2>{"Página no encontrada"}</h2>
-                <p>{"Lo sentimos, la página que buscas no existe."}</p>
+                <h2>{t.not_found_title}</h2>
+                <p>{t.not_found_text}</p>
                 <button class="btn mt-3" onclick={on_home_click}>
-                    {"Volver al inicio"}
+                    {t.back_home}
                 </button>
             </div>
         </div>
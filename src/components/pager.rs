@@ -0,0 +1,67 @@
+use yew::prelude::*;
+
+use crate::contexts::use_i18n;
+
+#[derive(Properties, PartialEq)]
+pub struct PagerProps {
+    pub page: u32,
+    pub total_pages: u32,
+    pub on_change: Callback<u32>,
+}
+
+#[function_component(Pager)]
+pub fn pager(props: &PagerProps) -> Html {
+    let i18n = use_i18n();
+    let t = &i18n.t().pager;
+
+    if props.total_pages <= 1 {
+        return html! {};
+    }
+
+    let page_button = |page: u32, label: String, disabled: bool| {
+        let on_change = props.on_change.clone();
+        let is_current = page == props.page;
+        html! {
+            <button
+                class={if is_current { "btn btn-sm" } else { "btn btn-sm btn-outline" }}
+                aria-current={if is_current { "page" } else { "false" }}
+                disabled={disabled || is_current}
+                onclick={Callback::from(move |_: MouseEvent| on_change.emit(page))}
+            >
+                {label}
+            </button>
+        }
+    };
+
+    // First, last and a window of two pages around the current one
+    let pages: Vec<u32> = (1..=props.total_pages)
+        .filter(|page| *page == 1 || *page == props.total_pages || page.abs_diff(props.page) <= 2)
+        .collect();
+
+    let mut previous = 0;
+    let numbered = pages
+        .into_iter()
+        .map(|page| {
+            let gap = page > previous + 1;
+            previous = page;
+            html! {
+                <>
+                    {if gap { html! { <span class="pager-gap">{"…"}</span> } } else { html! {} }}
+                    {page_button(page, page.to_string(), false)}
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    // The page comes from the URL, so it may be huge or past the last page
+    let previous_page = props.page.saturating_sub(1).clamp(1, props.total_pages);
+    let next_page = props.page.saturating_add(1).min(props.total_pages);
+
+    html! {
+        <nav class="admin-actions pager" aria-label={t.label}>
+            {page_button(previous_page, t.previous.to_string(), props.page <= 1)}
+            {numbered}
+            {page_button(next_page, t.next.to_string(), props.page >= props.total_pages)}
+        </nav>
+    }
+}
@@ -1,197 +1,201 @@
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use crate::components::{choice_options, ChangePassword, SelectField, TextField};
+use crate::contexts::use_i18n;
+use crate::form::{add_error, email_error, use_form, FormErrors, FormValues};
 use crate::services::api::ApiService;
-use crate::types::UpdateUserRequest;
-use crate::utils::validate_password;
+use crate::services::error::ApiError;
+use crate::types::{Attendance, Choice, UpdateUserRequest, User};
+
+fn profile_values(user: &User) -> FormValues {
+    FormValues::new(&[
+        ("email", &user.email),
+        ("full_name", &user.full_name),
+        ("identification", &user.identification),
+        ("attendance", user.attendance.key()),
+    ])
+}
+
+fn validate_profile(values: &FormValues) -> FormErrors {
+    let mut errors = FormErrors::new();
+    add_error(&mut errors, "email", email_error(values.get("email")));
+    errors
+}
 
 #[function_component(Profile)]
 pub fn profile() -> Html {
-    let email = use_state(|| String::new());
-    let full_name = use_state(|| String::new());
-    let identification = use_state(|| String::new());
-    let password = use_state(|| String::new());
-    let repeated_password = use_state(|| String::new());
-    let attendance = use_state(|| "remote".to_string());
-    let message = use_state(|| String::new());
+    let user = use_state(|| None::<User>);
+    let form = use_form(
+        || FormValues::new(&[("attendance", Attendance::default().key())]),
+        validate_profile,
+    );
+    let message = use_state(String::new);
+    let loading = use_state(|| true);
+    let i18n = use_i18n();
+    let t = i18n.t();
 
     // Fill form with existing user data on mount
     {
-        let full_name = full_name.clone();
-        let identification = identification.clone();
+        let user = user.clone();
+        let form = form.clone();
+        let message = message.clone();
+        let loading = loading.clone();
         use_effect_with_deps(
             move |_| {
-                // In a real app, you'd fetch current user data here
-                // For now, we'll just set empty values
-                full_name.set(String::new());
-                identification.set(String::new());
+                spawn_local(async move {
+                    match ApiService::get_current_user().await {
+                        Ok(user_data) => {
+                            form.reset(profile_values(&user_data));
+                            user.set(Some(user_data));
+                        }
+                        Err(error) => {
+                            message.set((t.profile.load_error)(&error.to_string()));
+                        }
+                    }
+                    loading.set(false);
+                });
                 || ()
             },
             (),
         );
     }
 
-    let on_email_change = {
-        let email = email.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            email.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_password_change = {
-        let password = password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            password.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_repeated_password_change = {
-        let repeated_password = repeated_password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            repeated_password.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_attendance_change = {
-        let attendance = attendance.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            attendance.set(select.value());
-        })
-    };
-
     let on_submit = {
-        let email = email.clone();
-        let password = password.clone();
-        let repeated_password = repeated_password.clone();
-        let attendance = attendance.clone();
+        let user = user.clone();
+        let form = form.clone();
         let message = message.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let email_val = (*email).clone();
-            let password_val = (*password).clone();
-            let repeated_password_val = (*repeated_password).clone();
-            let attendance_val = (*attendance).clone();
-            let message = message.clone();
-
-            if !validate_password(&password_val) || !validate_password(&repeated_password_val) {
-                message.set("Contraseña no valida".to_string());
+            let Some(current) = (*user).clone() else {
                 return;
-            }
-
-            if password_val != repeated_password_val {
-                message.set("Contraseñas no coinciden".to_string());
+            };
+            let Some(values) = form.submit() else {
+                message.set(String::new());
                 return;
-            }
+            };
+            let user = user.clone();
+            let form = form.clone();
+            let message = message.clone();
 
+            // Only the fields that changed since the profile loaded
             let data = UpdateUserRequest {
-                email: email_val,
-                password: password_val,
-                attendance: attendance_val,
+                email: form
+                    .is_dirty("email")
+                    .then(|| values.get("email").to_string()),
+                attendance: form
+                    .is_dirty("attendance")
+                    .then(|| values.choice("attendance")),
             };
 
+            if data.is_empty() {
+                message.set(t.profile.no_changes.to_string());
+                return;
+            }
+
             spawn_local(async move {
-                match ApiService::update_user(data).await {
+                match ApiService::update_user(data.clone()).await {
                     Ok(response) => {
                         log::info!("Success: {}", response);
-                        message.set("Perfil actualizado exitosamente".to_string());
+                        let mut updated = current;
+                        if let Some(email) = data.email {
+                            updated.email = email;
+                        }
+                        if let Some(attendance) = data.attendance {
+                            updated.attendance = attendance;
+                        }
+                        form.reset(profile_values(&updated));
+                        user.set(Some(updated));
+                        message.set(t.profile.success.to_string());
+                    }
+                    Err(ApiError::Conflict(_)) => {
+                        message.set(t.profile.duplicate_email.to_string());
                     }
                     Err(error) => {
-                        message.set(error);
+                        message.set(error.to_string());
                     }
                 }
             });
         })
     };
 
+    if *loading {
+        return html! {
+            <div class="container">
+                <div class="spinner"></div>
+                <p>{t.profile.loading}</p>
+            </div>
+        };
+    }
+
     html! {
         <>
-            <h1>{"Perfil"}</h1>
+            <h1>{t.profile.title}</h1>
+            {if let Some(user) = user.as_ref() {
+                html! {
+                    <section id="profile-summary" class="profile-info">
+                        <p><strong>{t.profile.role}</strong>{user.role().label()}</p>
+                        {if let Some(hours) = user.speaker_hours() {
+                            html! { <p><strong>{t.profile.hours}</strong>{hours}</p> }
+                        } else {
+                            html! {}
+                        }}
+                        <p>
+                            <strong>{t.profile.certificates}</strong>
+                            {(t.profile.certificate_summary)(
+                                if user.cert_generated.horizontal { t.common.yes } else { t.common.no },
+                                if user.cert_generated.vertical { t.common.yes } else { t.common.no },
+                            )}
+                        </p>
+                    </section>
+                }
+            } else {
+                html! {}
+            }}
             <section>
-                <form id="profile-form" onsubmit={on_submit}>
-                    <div class="form-group">
-                        <label for="email-input">{"Correo electrónico:"}</label>
-                        <input
-                            type="email"
-                            id="email-input"
-                            class="form-input"
-                            required={true}
-                            minlength="5"
-                            value={(*email).clone()}
-                            onchange={on_email_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="full-name-input">{"Nombre completo:"}</label>
-                        <input
-                            type="text"
-                            id="full-name-input"
-                            class="form-input"
-                            disabled={true}
-                            value={(*full_name).clone()}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="id-input">{"Documento de identificación:"}</label>
-                        <input
-                            type="text"
-                            id="id-input"
-                            class="form-input"
-                            disabled={true}
-                            value={(*identification).clone()}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="password-input">{"Contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*password).clone()}
-                            onchange={on_password_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="repeat-password-input">{"Repita contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="repeat-password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*repeated_password).clone()}
-                            onchange={on_repeated_password_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="attendance-select">{"Tipo de asistencia:"}</label>
-                        <select id="attendance-select" value={(*attendance).clone()} onchange={on_attendance_change}>
-                            <option value="remote">{"Remota"}</option>
-                            <option value="presential">{"Presencial"}</option>
-                        </select>
-                    </div>
+                <form id="profile-form" novalidate={true} onsubmit={on_submit}>
+                    <TextField
+                        form={form.clone()}
+                        name="email"
+                        id="email-input"
+                        label={t.fields.email}
+                        input_type="email"
+                        required={true}
+                        minlength="5"
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="full_name"
+                        id="full-name-input"
+                        label={t.fields.full_name}
+                        disabled={true}
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="identification"
+                        id="id-input"
+                        label={t.fields.identification}
+                        disabled={true}
+                    />
+                    <SelectField
+                        form={form.clone()}
+                        name="attendance"
+                        id="attendance-select"
+                        label={t.fields.attendance}
+                        options={choice_options(Attendance::ALL)}
+                    />
 
                     <div>
                         <span id="message-span">{(*message).clone()}</span>
                     </div>
 
-                    <button type="submit" id="register-btn">{"Actualizar"}</button>
+                    <button type="submit" id="register-btn">{t.profile.submit}</button>
                 </form>
             </section>
+
+            <ChangePassword />
         </>
     }
 }
@@ -1,31 +1,61 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use crate::routes::Route;
+use crate::components::Forbidden;
+use crate::contexts::{use_auth, use_i18n};
+use crate::routes::{LoginQuery, Route};
 use crate::services::auth::AuthService;
+use crate::types::Role;
 
 #[derive(Properties, PartialEq)]
 pub struct ProtectedRouteProps {
     pub children: Children,
+    // Roles allowed to see the route; empty means any signed-in user
+    #[prop_or_default]
+    pub roles: &'static [Role],
 }
 
 #[function_component(ProtectedRoute)]
 pub fn protected_route(props: &ProtectedRouteProps) -> Html {
     let navigator = use_navigator().unwrap();
-    let is_authenticated = use_state(|| AuthService::is_authenticated());
-    let id_authenticated_copy = *is_authenticated.clone();
+    let location = use_location().unwrap();
+    let auth = use_auth();
+    let i18n = use_i18n();
+    let is_authenticated = auth.is_authenticated();
 
-    use_effect_with_deps(
-        move |_| {
-            if !id_authenticated_copy {
-                navigator.push(&Route::Login);
-            }
-            || ()
-        },
-        id_authenticated_copy.clone(),
-    );
+    {
+        let auth = auth.clone();
+        use_effect_with_deps(
+            move |_| {
+                if !is_authenticated {
+                    // A stored token that no longer authenticates has expired
+                    let expired = AuthService::get_token().is_some();
+                    if expired {
+                        auth.logout();
+                    }
 
-    if *is_authenticated {
+                    let query = LoginQuery {
+                        expired,
+                        redirect: Some(format!("{}{}", location.path(), location.query_str())),
+                    };
+                    if navigator.push_with_query(&Route::Login, &query).is_err() {
+                        navigator.push(&Route::Login);
+                    }
+                }
+                || ()
+            },
+            is_authenticated,
+        );
+    }
+
+    let has_role = props.roles.is_empty()
+        || auth
+            .user()
+            .is_some_and(|user| user.has_any_role(props.roles));
+
+    if is_authenticated && !has_role {
+        html! { <Forbidden /> }
+    } else if is_authenticated {
         html! {
             <>
                 { for props.children.iter() }
@@ -35,7 +65,7 @@ pub fn protected_route(props: &ProtectedRouteProps) -> Html {
         html! {
             <div class="container">
                 <div class="spinner"></div>
-                <p>{"Verificando autenticación..."}</p>
+                <p>{i18n.t().common.checking_session}</p>
             </div>
         }
     }
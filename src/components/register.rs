@@ -1,151 +1,117 @@
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
+use crate::components::{choice_options, PasswordField, SelectField, TextField};
+use crate::contexts::use_i18n;
+use crate::form::{
+    add_error, email_error, hours_error, password_error, repeated_password_error, required_error,
+    use_form, FormErrors, FormValues,
+};
 use crate::services::api::ApiService;
-use crate::types::RegisterRequest;
-use crate::utils::validate_password;
-
-#[function_component(Register)]
-pub fn register() -> Html {
-    let email = use_state(|| String::new());
-    let full_name = use_state(|| String::new());
-    let identification = use_state(|| String::new());
-    let password = use_state(|| String::new());
-    let repeated_password = use_state(|| String::new());
-    let role = use_state(|| "attendee".to_string());
-    let presentation = use_state(|| String::new());
-    let attendance = use_state(|| "remote".to_string());
-    let message = use_state(|| String::new());
-
-    let on_email_change = {
-        let email = email.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            email.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_full_name_change = {
-        let full_name = full_name.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            full_name.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_identification_change = {
-        let identification = identification.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            identification.set(input.value());
-            message.set(String::new());
-        })
-    };
-
-    let on_password_change = {
-        let password = password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            password.set(input.value());
-            message.set(String::new());
-        })
-    };
+use crate::services::error::ApiError;
+use crate::types::{Attendance, Choice, RegisterRequest, Role};
+
+// Form values for a registration, also used to clear or prefill the form
+pub fn registration_values(data: &RegisterRequest) -> FormValues {
+    let presentation = data
+        .presentation
+        .map(|hours| hours.key())
+        .unwrap_or_default();
+
+    FormValues::new(&[
+        ("email", &data.email),
+        ("full_name", &data.full_name),
+        ("identification", &data.identification),
+        ("password", &data.password),
+        ("repeated_password", &data.password),
+        ("role", data.role.key()),
+        ("presentation", &presentation),
+        ("attendance", data.attendance.key()),
+    ])
+}
 
-    let on_repeated_password_change = {
-        let repeated_password = repeated_password.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            repeated_password.set(input.value());
-            message.set(String::new());
-        })
-    };
+pub fn registration_request(values: &FormValues) -> RegisterRequest {
+    let role = values.choice("role");
+
+    RegisterRequest {
+        email: values.get("email").to_string(),
+        full_name: values.get("full_name").to_string(),
+        identification: values.get("identification").to_string(),
+        password: values.get("password").to_string(),
+        role,
+        presentation: (role == Role::Speaker).then(|| values.hours("presentation")),
+        attendance: values.choice("attendance"),
+    }
+}
 
-    let on_role_change = {
-        let role = role.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            role.set(select.value());
-        })
-    };
+// Shared by the public and the admin registration forms
+pub fn validate_registration(values: &FormValues) -> FormErrors {
+    let mut errors = FormErrors::new();
+    let password = values.get("password");
+
+    add_error(&mut errors, "email", email_error(values.get("email")));
+    add_error(
+        &mut errors,
+        "full_name",
+        required_error(values.get("full_name")),
+    );
+    add_error(
+        &mut errors,
+        "identification",
+        required_error(values.get("identification")),
+    );
+    add_error(&mut errors, "password", password_error(password));
+    add_error(
+        &mut errors,
+        "repeated_password",
+        repeated_password_error(password, values.get("repeated_password")),
+    );
+    if values.choice::<Role>("role") == Role::Speaker {
+        add_error(
+            &mut errors,
+            "presentation",
+            hours_error(values.get("presentation")),
+        );
+    }
 
-    let on_presentation_change = {
-        let presentation = presentation.clone();
-        let message = message.clone();
-        Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target_unchecked_into();
-            presentation.set(input.value());
-            message.set(String::new());
-        })
-    };
+    errors
+}
 
-    let on_attendance_change = {
-        let attendance = attendance.clone();
-        Callback::from(move |e: Event| {
-            let select: HtmlSelectElement = e.target_unchecked_into();
-            attendance.set(select.value());
-        })
-    };
+#[function_component(Register)]
+pub fn register() -> Html {
+    let form = use_form(
+        || registration_values(&RegisterRequest::default()),
+        validate_registration,
+    );
+    let message = use_state(String::new);
+    let i18n = use_i18n();
+    let t = i18n.t();
 
     let on_submit = {
-        let email = email.clone();
-        let full_name = full_name.clone();
-        let identification = identification.clone();
-        let password = password.clone();
-        let repeated_password = repeated_password.clone();
-        let role = role.clone();
-        let presentation = presentation.clone();
-        let attendance = attendance.clone();
+        let form = form.clone();
         let message = message.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            let email_val = (*email).clone();
-            let full_name_val = (*full_name).clone();
-            let identification_val = (*identification).clone();
-            let password_val = (*password).clone();
-            let repeated_password_val = (*repeated_password).clone();
-            let role_val = (*role).clone();
-            let presentation_val = (*presentation).clone();
-            let attendance_val = (*attendance).clone();
-            let message = message.clone();
-
-            if !validate_password(&password_val) || !validate_password(&repeated_password_val) {
-                message.set("Contraseña no valida".to_string());
+            let Some(values) = form.submit() else {
+                message.set(String::new());
                 return;
-            }
-
-            if password_val != repeated_password_val {
-                message.set("Contraseñas no coinciden".to_string());
-                return;
-            }
-
-            let data = RegisterRequest {
-                email: email_val,
-                full_name: full_name_val,
-                identification: identification_val,
-                password: password_val,
-                role: role_val,
-                presentation: presentation_val,
-                attendance: attendance_val,
             };
+            let data = registration_request(&values);
+            let message = message.clone();
 
             spawn_local(async move {
                 match ApiService::register(data).await {
                     Ok(response) => {
                         log::info!("Success: {}", response);
-                        message.set("Usuario registrado exitosamente".to_string());
+                        message.set(t.register.success.to_string());
+                    }
+                    Err(ApiError::Conflict(_)) => {
+                        message.set(t.common.duplicate_user.to_string());
                     }
                     Err(error) => {
-                        message.set(error);
+                        message.set(error.to_string());
                     }
                 }
             });
@@ -154,102 +120,86 @@ pub fn register() -> Html {
 
     html! {
         <>
-            <h1>{"Registro"}</h1>
+            <h1>{t.register.title}</h1>
 
             <section>
-                <form id="register-form" onsubmit={on_submit}>
-                    <div class="form-group">
-                        <label for="email-input">{"Correo electrónico:"}</label>
-                        <input
-                            type="email"
-                            id="email-input"
-                            class="form-input"
-                            required={true}
-                            minlength="5"
-                            value={(*email).clone()}
-                            onchange={on_email_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="full-name-input">{"Nombre completo:"}</label>
-                        <input
-                            type="text"
-                            id="full-name-input"
-                            class="form-input"
-                            required={true}
-                            value={(*full_name).clone()}
-                            onchange={on_full_name_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="id-input">{"Documento de identificación:"}</label>
-                        <input
-                            type="text"
-                            id="id-input"
-                            class="form-input"
-                            required={true}
-                            value={(*identification).clone()}
-                            onchange={on_identification_change}
-                        />
-                    </div>
-
-                    <div class="form-group">
-                        <label for="password-input">{"Contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*password).clone()}
-                            onchange={on_password_change}
-                        />
-                    </div>
-                    <div class="form-group">
-                        <label for="repeat-password-input">{"Repita contraseña:"}</label>
-                        <input
-                            type="password"
-                            id="repeat-password-input"
-                            class="form-input"
-                            required={true}
-                            minlength="8"
-                            value={(*repeated_password).clone()}
-                            onchange={on_repeated_password_change}
-                        />
-                    </div>
-
-                    <div class="form-group">
-                        <label for="role-select">{"Rol:"}</label>
-                        <select id="role-select" value={(*role).clone()} onchange={on_role_change}>
-                            <option value="attendee">{"Asistente"}</option>
-                            <option value="speaker">{"Ponente"}</option>
-                        </select>
-                    </div>
-
-                    <div class={if *role != "speaker" { "form-group hidden" } else { "form-group" }}>
-                        <label for="hours-input">{"Cantidad de horas de la ponencia (0 por defecto):"}</label>
-                        <input
-                            type="number"
-                            id="hours-input"
-                            class="form-input"
-                            value={presentation.to_string()}
-                            onchange={on_presentation_change}
-                        />
-                    </div>
-
-                    <div class="form-group">
-                        <label for="attendance-select">{"Tipo de asistencia:"}</label>
-                        <select id="attendance-select" value={(*attendance).clone()} onchange={on_attendance_change}>
-                            <option value="remote">{"Remota"}</option>
-                            <option value="presential">{"Presencial"}</option>
-                        </select>
-                    </div>
+                <form id="register-form" novalidate={true} onsubmit={on_submit}>
+                    <TextField
+                        form={form.clone()}
+                        name="email"
+                        id="email-input"
+                        label={t.fields.email}
+                        input_type="email"
+                        required={true}
+                        minlength="5"
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="full_name"
+                        id="full-name-input"
+                        label={t.fields.full_name}
+                        required={true}
+                    />
+                    <TextField
+                        form={form.clone()}
+                        name="identification"
+                        id="id-input"
+                        label={t.fields.identification}
+                        required={true}
+                    />
+
+                    <PasswordField
+                        form={form.clone()}
+                        name="password"
+                        id="password-input"
+                        label={t.fields.password}
+                        required={true}
+                        minlength="8"
+                    />
+                    <PasswordField
+                        form={form.clone()}
+                        name="repeated_password"
+                        id="repeat-password-input"
+                        label={t.fields.repeat_password}
+                        required={true}
+                        minlength="8"
+                    />
+
+                    <SelectField
+                        form={form.clone()}
+                        name="role"
+                        id="role-select"
+                        label={t.fields.role}
+                        options={choice_options(&[Role::Attendee, Role::Speaker])}
+                    />
+
+                    {if form.values().choice::<Role>("role") == Role::Speaker {
+                        html! {
+                            <TextField
+                                form={form.clone()}
+                                name="presentation"
+                                id="hours-input"
+                                label={t.fields.hours}
+                                inputmode="decimal"
+                            />
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    <SelectField
+                        form={form.clone()}
+                        name="attendance"
+                        id="attendance-select"
+                        label={t.fields.attendance}
+                        options={choice_options(Attendance::ALL)}
+                    />
 
                     <div>
                         <span id="message-span">{(*message).clone()}</span>
                     </div>
 
-                    <button type="submit" id="register-btn">{"Registrarse"}</button>
+                    <button type="submit" id="register-btn">{t.register.submit}</button>
                 </form>
             </section>
         </>
@@ -0,0 +1,175 @@
+use gloo_timers::callback::Timeout;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::contexts::use_i18n;
+use crate::types::{Attendance, Choice, Role, UserQuery};
+
+// Typing pause before a search is applied; every applied search changes the
+// URL and fetches a new listing
+const SEARCH_DELAY_MS: u32 = 300;
+
+fn non_empty(value: String) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct UserFiltersProps {
+    pub query: UserQuery,
+    pub on_change: Callback<UserQuery>,
+}
+
+#[function_component(UserFilters)]
+pub fn user_filters(props: &UserFiltersProps) -> Html {
+    let i18n = use_i18n();
+    let t = &i18n.t().filters;
+
+    // What is typed in the search box, applied once typing pauses
+    let search = use_state(|| props.query.q.clone().unwrap_or_default());
+    let timer = use_mut_ref(|| None::<Timeout>);
+    let typing = use_mut_ref(|| false);
+    // Latest query, so a delayed search keeps filters changed meanwhile
+    let latest = use_mut_ref(UserQuery::default);
+    *latest.borrow_mut() = props.query.clone();
+
+    // Follow outside changes (clearing filters, back navigation) unless the
+    // user is still typing
+    {
+        let search = search.clone();
+        let typing = typing.clone();
+        use_effect_with_deps(
+            move |q: &Option<String>| {
+                if !*typing.borrow() {
+                    search.set(q.clone().unwrap_or_default());
+                }
+                || ()
+            },
+            props.query.q.clone(),
+        );
+    }
+
+    let on_search_input = {
+        let search = search.clone();
+        let timer = timer.clone();
+        let typing = typing.clone();
+        let latest = latest.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let value = input.value();
+            search.set(value.clone());
+
+            *typing.borrow_mut() = true;
+            let typing = typing.clone();
+            let latest = latest.clone();
+            let on_change = on_change.clone();
+            // Replacing the timer cancels the previous one
+            *timer.borrow_mut() = Some(Timeout::new(SEARCH_DELAY_MS, move || {
+                *typing.borrow_mut() = false;
+                let query = latest.borrow().clone();
+                on_change.emit(UserQuery {
+                    q: non_empty(value),
+                    page: None,
+                    ..query
+                });
+            }));
+        })
+    };
+
+    let on_role_change = {
+        let query = props.query.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_change.emit(UserQuery {
+                role: Role::from_key(&select.value()),
+                page: None,
+                ..query.clone()
+            });
+        })
+    };
+
+    let on_attendance_change = {
+        let query = props.query.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_change.emit(UserQuery {
+                attendance: Attendance::from_key(&select.value()),
+                page: None,
+                ..query.clone()
+            });
+        })
+    };
+
+    let on_cert_change = {
+        let query = props.query.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_change.emit(UserQuery {
+                cert: non_empty(select.value()),
+                page: None,
+                ..query.clone()
+            });
+        })
+    };
+
+    let on_clear = {
+        let timer = timer.clone();
+        let typing = typing.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            timer.borrow_mut().take();
+            *typing.borrow_mut() = false;
+            on_change.emit(UserQuery::default());
+        })
+    };
+
+    let query = &props.query;
+
+    html! {
+        <section id="filters-section" class="admin-actions">
+            <input
+                type="search"
+                id="search-input"
+                class="form-input"
+                placeholder={t.search_placeholder}
+                aria-label={t.search_label}
+                value={(*search).clone()}
+                oninput={on_search_input}
+            />
+            <select id="role-filter" aria-label={t.role_label} onchange={on_role_change}>
+                <option value="" selected={query.role.is_none()}>{t.all_roles}</option>
+                {for Role::ALL.iter().map(|role| html! {
+                    <option value={role.key()} selected={query.role == Some(*role)}>{role.label()}</option>
+                })}
+            </select>
+            <select id="attendance-filter" aria-label={t.attendance_label} onchange={on_attendance_change}>
+                <option value="" selected={query.attendance.is_none()}>{t.all_attendance}</option>
+                {for Attendance::ALL.iter().map(|attendance| html! {
+                    <option value={attendance.key()} selected={query.attendance == Some(*attendance)}>
+                        {attendance.label()}
+                    </option>
+                })}
+            </select>
+            <select id="cert-filter" aria-label={t.certificates_label} onchange={on_cert_change}>
+                <option value="" selected={query.cert.is_none()}>{t.all_certificates}</option>
+                {for [
+                    ("none", t.no_certificates),
+                    ("partial", t.one_certificate),
+                    ("complete", t.both_certificates),
+                ].into_iter().map(|(value, label)| html! {
+                    <option {value} selected={query.cert.as_deref() == Some(value)}>{label}</option>
+                })}
+            </select>
+            <button class="btn btn-outline btn-sm" onclick={on_clear} disabled={!query.is_filtered()}>
+                {t.clear}
+            </button>
+        </section>
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::OnceLock;
+
+use gloo_net::http::Request;
+use serde::Deserialize;
+
+// Fallbacks when nothing else is configured
+const DEVELOPMENT_API_URL: &str = "http://localhost:8000";
+const PRODUCTION_API_URL: &str = "https://apisimposiorevsalud.univsalud.online";
+
+static API_BASE_URL: OnceLock<String> = OnceLock::new();
+static EVENT_CONTENT_PATH: OnceLock<Option<String>> = OnceLock::new();
+
+// Contents of /config.json, which can be edited on the server after a build
+#[derive(Debug, Default, Deserialize)]
+struct RuntimeConfig {
+    #[serde(default)]
+    api_base_url: Option<String>,
+    // API path serving the Home page content, e.g. "/event"; without it the
+    // content bundled with the build is shown
+    #[serde(default)]
+    event_content_path: Option<String>,
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().trim_end_matches('/').to_string())
+        .filter(|value| !value.is_empty())
+}
+
+async fn runtime_config() -> Option<RuntimeConfig> {
+    let response = Request::get("/config.json")
+        .header("Cache-Control", "no-cache")
+        .send()
+        .await
+        .ok()?;
+    if !response.ok() {
+        return None;
+    }
+    // The dev server answers unknown paths with index.html, which simply
+    // fails to parse here
+    response.json().await.ok()
+}
+
+// <meta name="api-base-url" content="..."> in index.html
+fn meta_url() -> Option<String> {
+    let meta = gloo_utils::document()
+        .query_selector("meta[name='api-base-url']")
+        .ok()??;
+    non_empty(meta.get_attribute("content"))
+}
+
+// API_BASE_URL in the environment of `trunk build` / `trunk serve`
+fn build_time_url() -> Option<String> {
+    non_empty(option_env!("API_BASE_URL").map(str::to_string))
+}
+
+fn default_url() -> String {
+    if cfg!(debug_assertions) {
+        DEVELOPMENT_API_URL.to_string()
+    } else {
+        PRODUCTION_API_URL.to_string()
+    }
+}
+
+// Resolves the backend URL once on startup, before the app renders. In order
+// of precedence: /config.json, the <meta> tag, the build-time variable and
+// the default for the build profile. A path such as "/api" keeps requests
+// on the same origin, behind the Trunk (or hosting) proxy
+pub async fn load() {
+    let runtime = runtime_config().await.unwrap_or_default();
+    let url = match non_empty(runtime.api_base_url) {
+        Some(url) => url,
+        None => meta_url()
+            .or_else(build_time_url)
+            .unwrap_or_else(default_url),
+    };
+
+    log::info!("Using API at {}", url);
+    if API_BASE_URL.set(url).is_err() {
+        log::warn!("API base URL was already set");
+    }
+    let _ = EVENT_CONTENT_PATH.set(non_empty(runtime.event_content_path));
+}
+
+pub fn api_base_url() -> &'static str {
+    API_BASE_URL.get_or_init(|| {
+        meta_url()
+            .or_else(build_time_url)
+            .unwrap_or_else(default_url)
+    })
+}
+
+pub fn event_content_path() -> Option<&'static str> {
+    EVENT_CONTENT_PATH.get().and_then(Option::as_deref)
+}
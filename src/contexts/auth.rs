@@ -0,0 +1,105 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::services::auth::AuthService;
+use crate::types::Claims;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthState {
+    pub token: Option<String>,
+    pub user: Option<Claims>,
+}
+
+impl AuthState {
+    // Snapshot of the session persisted by `AuthService`
+    fn load() -> Self {
+        match AuthService::current_user() {
+            Some(user) => AuthState {
+                token: AuthService::get_token(),
+                user: Some(user),
+            },
+            None => AuthState {
+                token: None,
+                user: None,
+            },
+        }
+    }
+}
+
+// Shared session handle; every consumer re-renders when it changes
+#[derive(Clone, PartialEq)]
+pub struct AuthContext {
+    state: UseStateHandle<AuthState>,
+}
+
+impl AuthContext {
+    pub fn user(&self) -> Option<&Claims> {
+        self.state.user.as_ref()
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.state.user.is_some()
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.user().is_some_and(Claims::is_admin)
+    }
+
+    // False when the token could not be read and no session was started
+    pub fn login(&self, token: String) -> bool {
+        let stored = AuthService::set_token(token);
+        self.refresh();
+        stored
+    }
+
+    pub fn logout(&self) {
+        AuthService::logout();
+        self.refresh();
+    }
+
+    // Re-reads the persisted session, e.g. after the API layer expired it
+    pub fn refresh(&self) {
+        let current = AuthState::load();
+        if *self.state != current {
+            self.state.set(current);
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct AuthProviderProps {
+    pub children: Children,
+}
+
+// Must be mounted inside the router: the session is re-synced from storage on
+// every navigation so changes made outside components (401 handling) show up
+#[function_component(AuthProvider)]
+pub fn auth_provider(props: &AuthProviderProps) -> Html {
+    let state = use_state(AuthState::load);
+    let context = AuthContext { state };
+    let path = use_location()
+        .map(|location| location.path().to_string())
+        .unwrap_or_default();
+
+    {
+        let context = context.clone();
+        use_effect_with_deps(
+            move |_| {
+                context.refresh();
+                || ()
+            },
+            path,
+        );
+    }
+
+    html! {
+        <ContextProvider<AuthContext> context={context}>
+            { for props.children.iter() }
+        </ContextProvider<AuthContext>>
+    }
+}
+
+#[hook]
+pub fn use_auth() -> AuthContext {
+    use_context::<AuthContext>().expect("use_auth must be used inside AuthProvider")
+}
@@ -0,0 +1,53 @@
+use yew::prelude::*;
+
+use crate::i18n::{self, Language, Messages};
+
+// Current language; every consumer re-renders when it changes
+#[derive(Clone, PartialEq)]
+pub struct I18nContext {
+    language: UseStateHandle<Language>,
+}
+
+impl I18nContext {
+    pub fn language(&self) -> Language {
+        *self.language
+    }
+
+    pub fn t(&self) -> &'static Messages {
+        self.language.messages()
+    }
+
+    // Applied before the state update so labels built outside components
+    // are already in the new language when consumers re-render
+    pub fn set_language(&self, language: Language) {
+        i18n::apply(language);
+        i18n::save(language);
+        self.language.set(language);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct I18nProviderProps {
+    pub children: Children,
+}
+
+#[function_component(I18nProvider)]
+pub fn i18n_provider(props: &I18nProviderProps) -> Html {
+    let language = use_state(|| {
+        let language = Language::initial();
+        i18n::apply(language);
+        language
+    });
+    let context = I18nContext { language };
+
+    html! {
+        <ContextProvider<I18nContext> context={context}>
+            { for props.children.iter() }
+        </ContextProvider<I18nContext>>
+    }
+}
+
+#[hook]
+pub fn use_i18n() -> I18nContext {
+    use_context::<I18nContext>().expect("use_i18n must be used inside I18nProvider")
+}
@@ -0,0 +1,7 @@
+pub mod auth;
+pub mod i18n;
+pub mod user_cache;
+
+pub use auth::{use_auth, AuthProvider};
+pub use i18n::{use_i18n, I18nProvider};
+pub use user_cache::{use_user_cache, UserCacheContext, UserCacheProvider};
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::contexts::use_auth;
+use crate::types::{Page, User, UserQuery};
+
+// Ids of a listing page; the records themselves live in `UserCache::users`
+#[derive(Debug, Clone, PartialEq)]
+struct CachedPage {
+    ids: Vec<String>,
+    total: u64,
+    page: u32,
+    limit: u32,
+}
+
+// Client-side copy of the user records the admin pages have seen, keyed by
+// id, so moving between the list and the edit page needs no refetch
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserCache {
+    users: HashMap<String, User>,
+    pages: HashMap<UserQuery, CachedPage>,
+}
+
+pub enum UserCacheAction {
+    StorePage(UserQuery, Page<User>),
+    StoreUser(User),
+    RemoveUser(String),
+    // Drops listings (e.g. after bulk changes) but keeps known records
+    Invalidate,
+    Clear,
+}
+
+impl Reducible for UserCache {
+    type Action = UserCacheAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut cache = (*self).clone();
+        match action {
+            UserCacheAction::StorePage(query, page) => {
+                let ids = page.items.iter().map(|user| user.id.clone()).collect();
+                for user in page.items {
+                    cache.users.insert(user.id.clone(), user);
+                }
+                cache.pages.insert(
+                    query,
+                    CachedPage {
+                        ids,
+                        total: page.total,
+                        page: page.page,
+                        limit: page.limit,
+                    },
+                );
+            }
+            UserCacheAction::StoreUser(user) => {
+                cache.users.insert(user.id.clone(), user);
+            }
+            // Listings keep the id, so storing the user again restores it
+            UserCacheAction::RemoveUser(id) => {
+                cache.users.remove(&id);
+            }
+            UserCacheAction::Invalidate => cache.pages.clear(),
+            UserCacheAction::Clear => cache = UserCache::default(),
+        }
+        Rc::new(cache)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct UserCacheContext {
+    cache: UseReducerHandle<UserCache>,
+}
+
+impl UserCacheContext {
+    pub fn user(&self, id: &str) -> Option<User> {
+        self.cache.users.get(id).cloned()
+    }
+
+    // Last known version of a listing; users removed since are left out
+    pub fn page(&self, query: &UserQuery) -> Option<Page<User>> {
+        let cached = self.cache.pages.get(query)?;
+        let items: Vec<User> = cached
+            .ids
+            .iter()
+            .filter_map(|id| self.cache.users.get(id).cloned())
+            .collect();
+        let removed = (cached.ids.len() - items.len()) as u64;
+
+        Some(Page {
+            items,
+            total: cached.total.saturating_sub(removed),
+            page: cached.page,
+            limit: cached.limit,
+        })
+    }
+
+    pub fn store_page(&self, query: UserQuery, page: Page<User>) {
+        self.cache.dispatch(UserCacheAction::StorePage(query, page));
+    }
+
+    pub fn store_user(&self, user: User) {
+        self.cache.dispatch(UserCacheAction::StoreUser(user));
+    }
+
+    pub fn remove_user(&self, id: &str) {
+        self.cache
+            .dispatch(UserCacheAction::RemoveUser(id.to_string()));
+    }
+
+    pub fn invalidate(&self) {
+        self.cache.dispatch(UserCacheAction::Invalidate);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct UserCacheProviderProps {
+    pub children: Children,
+}
+
+// Must be mounted inside `AuthProvider`: the cache is dropped whenever the
+// signed-in user changes
+#[function_component(UserCacheProvider)]
+pub fn user_cache_provider(props: &UserCacheProviderProps) -> Html {
+    let cache = use_reducer(UserCache::default);
+    let user_id = use_auth().user().map(|user| user.id.clone());
+
+    {
+        let cache = cache.clone();
+        use_effect_with_deps(
+            move |_| {
+                cache.dispatch(UserCacheAction::Clear);
+                || ()
+            },
+            user_id,
+        );
+    }
+
+    html! {
+        <ContextProvider<UserCacheContext> context={UserCacheContext { cache }}>
+            { for props.children.iter() }
+        </ContextProvider<UserCacheContext>>
+    }
+}
+
+#[hook]
+pub fn use_user_cache() -> UserCacheContext {
+    use_context::<UserCacheContext>().expect("use_user_cache must be used inside UserCacheProvider")
+}
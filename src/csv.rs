@@ -0,0 +1,254 @@
+use crate::i18n;
+use crate::types::{Choice, User};
+
+// Lets Excel detect UTF-8 instead of the system code page
+const UTF8_BOM: &str = "\u{feff}";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsvFormat {
+    // Comma separated, for spreadsheets and scripts in general
+    Standard,
+    // Semicolon separated, which Excel expects under Spanish locales
+    Excel,
+}
+
+impl CsvFormat {
+    fn delimiter(&self) -> char {
+        match self {
+            CsvFormat::Standard => ',',
+            CsvFormat::Excel => ';',
+        }
+    }
+}
+
+// Spreadsheets run cells starting with these as formulas
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+fn escape_field(value: &str, delimiter: char) -> String {
+    // Names and emails come from public sign-ups, so a leading quote keeps
+    // anything formula-like as plain text
+    let value = if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains(delimiter) || value.contains(['"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn write_row(out: &mut String, fields: &[&str], delimiter: char) {
+    let row = fields
+        .iter()
+        .map(|field| escape_field(field, delimiter))
+        .collect::<Vec<_>>()
+        .join(&delimiter.to_string());
+    out.push_str(&row);
+    out.push_str("\r\n");
+}
+
+pub fn users_to_csv(users: &[User], format: CsvFormat) -> String {
+    let delimiter = format.delimiter();
+    let t = i18n::messages();
+    let yes_no = |generated: bool| if generated { t.common.yes } else { t.common.no };
+
+    let mut out = String::from(UTF8_BOM);
+    write_row(
+        &mut out,
+        &[
+            t.columns.id,
+            t.columns.email,
+            t.columns.name,
+            t.columns.identification,
+            t.columns.role,
+            t.columns.hours,
+            t.columns.attendance,
+            t.columns.horizontal_certificate,
+            t.columns.vertical_certificate,
+        ],
+        delimiter,
+    );
+
+    for user in users {
+        write_row(
+            &mut out,
+            &[
+                &user.id,
+                &user.email,
+                &user.full_name,
+                &user.identification,
+                user.role().label(),
+                &user
+                    .speaker_hours()
+                    .map(|hours| hours.to_string())
+                    .unwrap_or_default(),
+                user.attendance.label(),
+                yes_no(user.cert_generated.horizontal),
+                yes_no(user.cert_generated.vertical),
+            ],
+            delimiter,
+        );
+    }
+
+    out
+}
+
+// A parsed record and the 1-based line of the file where it starts, which
+// differs from its position once blank lines or quoted line breaks appear
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRow {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+// Parses CSV text into rows of fields. Handles quoted fields (with escaped
+// quotes and line breaks), a leading BOM, and either comma or semicolon
+// delimiters, detected from the first line
+pub fn parse_csv(text: &str) -> Vec<CsvRow> {
+    let text = text.strip_prefix(UTF8_BOM).unwrap_or(text);
+    let first_line = text.lines().next().unwrap_or_default();
+    let delimiter = if first_line.matches(';').count() > first_line.matches(',').count() {
+        ';'
+    } else {
+        ','
+    };
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                rows.push(CsvRow {
+                    line: row_line,
+                    fields: std::mem::take(&mut row),
+                });
+                line += 1;
+                row_line = line;
+            }
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(CsvRow {
+            line: row_line,
+            fields: row,
+        });
+    }
+
+    // Blank lines carry no data
+    rows.retain(|row| row.fields.iter().any(|field| !field.trim().is_empty()));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_delimiters_quotes_and_line_breaks() {
+        assert_eq!(escape_field("Ana", ','), "Ana");
+        assert_eq!(escape_field("Pérez, Ana", ','), "\"Pérez, Ana\"");
+        assert_eq!(escape_field("Pérez, Ana", ';'), "Pérez, Ana");
+        assert_eq!(escape_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("two\nlines", ';'), "\"two\nlines\"");
+    }
+
+    fn fields(rows: &[CsvRow]) -> Vec<Vec<&str>> {
+        rows.iter()
+            .map(|row| row.fields.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    fn lines(rows: &[CsvRow]) -> Vec<usize> {
+        rows.iter().map(|row| row.line).collect()
+    }
+
+    #[test]
+    fn parses_comma_separated_rows() {
+        let rows = parse_csv("email,name\r\nana@example.com,Ana\r\n");
+        assert_eq!(
+            fields(&rows),
+            [vec!["email", "name"], vec!["ana@example.com", "Ana"]]
+        );
+    }
+
+    #[test]
+    fn detects_semicolons_from_the_first_line() {
+        let rows = parse_csv("email;name\nana@example.com;Pérez, Ana");
+        assert_eq!(
+            fields(&rows),
+            [vec!["email", "name"], vec!["ana@example.com", "Pérez, Ana"]]
+        );
+    }
+
+    #[test]
+    fn strips_the_bom() {
+        let rows = parse_csv("\u{feff}email,name\na@b.co,Ana");
+        assert_eq!(fields(&rows)[0], ["email", "name"]);
+    }
+
+    #[test]
+    fn reads_quoted_fields() {
+        let rows = parse_csv("name,notes\n\"Pérez, Ana\",\"say \"\"hi\"\"\nbye\"\nLuis,\n");
+        assert_eq!(
+            fields(&rows),
+            [
+                vec!["name", "notes"],
+                vec!["Pérez, Ana", "say \"hi\"\nbye"],
+                vec!["Luis", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_the_file_line_of_each_row() {
+        let rows = parse_csv("name,notes\n\nAna,\"two\nlines\"\n  \nLuis,x\n");
+        assert_eq!(lines(&rows), [1, 3, 6]);
+    }
+
+    #[test]
+    fn round_trips_exported_fields() {
+        let mut out = String::from(UTF8_BOM);
+        write_row(&mut out, &["a;b", "\"q\"", "x\ny"], ';');
+        write_row(&mut out, &["1", "2", "3"], ';');
+        assert_eq!(
+            fields(&parse_csv(&out)),
+            [vec!["a;b", "\"q\"", "x\ny"], vec!["1", "2", "3"]]
+        );
+    }
+
+    #[test]
+    fn neutralizes_formulas() {
+        assert_eq!(
+            escape_field("=HYPERLINK(\"x\")", ';'),
+            "\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(escape_field("+1", ','), "'+1");
+        assert_eq!(escape_field("-2+3", ','), "'-2+3");
+        assert_eq!(escape_field("@SUM(A1)", ','), "'@SUM(A1)");
+        assert_eq!(escape_field("\tcmd", ','), "'\tcmd");
+        assert_eq!(escape_field("a=b", ','), "a=b");
+    }
+}
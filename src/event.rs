@@ -0,0 +1,181 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::i18n::{self, Language};
+
+// Shipped with the build; an API endpoint can replace it at runtime (see
+// `config::event_content_path`)
+const BUNDLED_CONTENT: &str = include_str!("../content/event.json");
+
+// Event details shown on the Home page
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventContent {
+    pub title: Text,
+    pub subtitle: Text,
+    pub banner: Image,
+    pub flyer: Image,
+    pub speakers: Vec<Speaker>,
+    pub location: Location,
+    pub date: Text,
+    pub starts: Time,
+    pub ends: Time,
+    pub schedule: Vec<Session>,
+    pub contact_email: String,
+    pub organizers: Vec<Text>,
+}
+
+impl EventContent {
+    pub fn bundled() -> Self {
+        serde_json::from_str(BUNDLED_CONTENT).expect("content/event.json is not valid")
+    }
+
+    // Sessions split into the morning and afternoon lists of the Home page
+    pub fn schedule_halves(&self) -> (Vec<&Session>, Vec<&Session>) {
+        self.schedule
+            .iter()
+            .partition(|session| session.start.hour < 12)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Speaker {
+    pub name: String,
+    pub title: Text,
+    #[serde(default)]
+    pub bio: Option<Text>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Session {
+    pub start: Time,
+    pub title: Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Location {
+    // Venue and address, one line each
+    pub lines: Vec<Text>,
+    pub modality: Text,
+    pub streaming: Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Image {
+    pub src: String,
+    pub alt: Text,
+}
+
+// Either one text for every language (names, addresses) or one per language.
+// Spanish is required; a missing translation falls back to it
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Text {
+    Same(String),
+    Translated {
+        es: String,
+        #[serde(default)]
+        pt: Option<String>,
+        #[serde(default)]
+        en: Option<String>,
+    },
+}
+
+impl Text {
+    pub fn get(&self, language: Language) -> &str {
+        match self {
+            Text::Same(text) => text,
+            Text::Translated { es, pt, en } => match language {
+                Language::Es => es,
+                Language::Pt => pt.as_deref().unwrap_or(es),
+                Language::En => en.as_deref().unwrap_or(es),
+            },
+        }
+    }
+}
+
+// Time of day, written "HH:MM" in the content file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    fn parse(value: &str) -> Option<Time> {
+        let (hour, minute) = value.trim().split_once(':')?;
+        let time = Time {
+            hour: hour.parse().ok()?,
+            minute: minute.parse().ok()?,
+        };
+        (time.hour < 24 && time.minute < 60).then_some(time)
+    }
+}
+
+// "2:00 PM" or "14:00", as the current language writes it
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if i18n::messages().twelve_hour_clock {
+            let suffix = if self.hour < 12 { "AM" } else { "PM" };
+            let hour = match self.hour % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            write!(f, "{}:{:02} {}", hour, self.minute, suffix)
+        } else {
+            write!(f, "{}:{:02}", self.hour, self.minute)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Time::parse(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid time \"{}\"", value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_content_is_valid() {
+        let content: EventContent = serde_json::from_str(BUNDLED_CONTENT)
+            .unwrap_or_else(|error| panic!("content/event.json: {}", error));
+        assert!(!content.schedule.is_empty());
+        assert!(content.starts < content.ends);
+    }
+
+    #[test]
+    fn parses_times() {
+        assert_eq!(
+            Time::parse("08:30"),
+            Some(Time {
+                hour: 8,
+                minute: 30
+            })
+        );
+        assert_eq!(
+            Time::parse(" 14:05 "),
+            Some(Time {
+                hour: 14,
+                minute: 5
+            })
+        );
+        assert_eq!(Time::parse("24:00"), None);
+        assert_eq!(Time::parse("12:60"), None);
+        assert_eq!(Time::parse("2 PM"), None);
+    }
+
+    #[test]
+    fn falls_back_to_spanish() {
+        let text: Text = serde_json::from_str(r#"{"es": "Hola", "en": "Hello"}"#).unwrap();
+        assert_eq!(text.get(Language::En), "Hello");
+        assert_eq!(text.get(Language::Pt), "Hola");
+
+        let same: Text = serde_json::from_str(r#""Pasto""#).unwrap();
+        assert_eq!(same.get(Language::Pt), "Pasto");
+    }
+}
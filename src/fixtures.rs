@@ -0,0 +1,20 @@
+// Sample data for local testing. Only compiled with the `dev-fixtures`
+// feature, so none of it can reach a deployed build
+use crate::types::{Attendance, RegisterRequest, Role};
+use crate::utils::generate_password;
+
+// Unique email and identification on every call so repeated use does not
+// collide with earlier test accounts
+pub fn sample_registration() -> RegisterRequest {
+    let stamp = js_sys::Date::now() as u64;
+
+    RegisterRequest {
+        email: format!("prueba+{}@example.com", stamp),
+        full_name: "Usuario de Prueba".to_string(),
+        identification: (stamp % 10_000_000_000).to_string(),
+        password: generate_password(12).unwrap_or_default(),
+        role: Role::Attendee,
+        presentation: None,
+        attendance: Attendance::Remote,
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::i18n;
+use crate::types::{Choice, Hours};
+use crate::utils::{password_issues, validate_email};
+
+// Current value of every field of a form, by field name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormValues(HashMap<&'static str, String>);
+
+impl FormValues {
+    pub fn new(fields: &[(&'static str, &str)]) -> Self {
+        FormValues(
+            fields
+                .iter()
+                .map(|(name, value)| (*name, value.to_string()))
+                .collect(),
+        )
+    }
+
+    // Missing fields read as empty
+    pub fn get(&self, name: &str) -> &str {
+        self.0.get(name).map(String::as_str).unwrap_or_default()
+    }
+
+    pub fn set(&mut self, name: &'static str, value: String) {
+        self.0.insert(name, value);
+    }
+
+    // Value of a select built with `choice_options`; its options are the
+    // choice keys, so the default is only hit for a missing field
+    pub fn choice<T: Choice + Default>(&self, name: &str) -> T {
+        T::from_key(self.get(name)).unwrap_or_default()
+    }
+
+    // Hours of a field already checked with `hours_error`
+    pub fn hours(&self, name: &str) -> Hours {
+        Hours::parse(self.get(name)).unwrap_or_default()
+    }
+}
+
+// Error message per field; fields without an entry are valid
+pub type FormErrors = HashMap<&'static str, String>;
+
+// Checks the whole form at once so rules can compare fields
+pub type Validator = fn(&FormValues) -> FormErrors;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormState {
+    initial: FormValues,
+    values: FormValues,
+    touched: HashSet<&'static str>,
+    submitted: bool,
+}
+
+pub enum FormAction {
+    Set(&'static str, String),
+    Touch(&'static str),
+    Submit,
+    // New starting point, e.g. once the record being edited has loaded
+    Reset(FormValues),
+}
+
+impl Reducible for FormState {
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            FormAction::Set(name, value) => state.values.set(name, value),
+            FormAction::Touch(name) => {
+                state.touched.insert(name);
+            }
+            FormAction::Submit => state.submitted = true,
+            FormAction::Reset(values) => {
+                state = FormState {
+                    initial: values.clone(),
+                    values,
+                    touched: HashSet::new(),
+                    submitted: false,
+                };
+            }
+        }
+        Rc::new(state)
+    }
+}
+
+#[derive(Clone)]
+pub struct UseForm {
+    state: UseReducerHandle<FormState>,
+    validate: Validator,
+}
+
+impl PartialEq for UseForm {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl UseForm {
+    pub fn value(&self, name: &str) -> String {
+        self.state.values.get(name).to_string()
+    }
+
+    pub fn values(&self) -> FormValues {
+        self.state.values.clone()
+    }
+
+    pub fn set(&self, name: &'static str, value: String) {
+        self.state.dispatch(FormAction::Set(name, value));
+    }
+
+    pub fn touch(&self, name: &'static str) {
+        self.state.dispatch(FormAction::Touch(name));
+    }
+
+    pub fn reset(&self, values: FormValues) {
+        self.state.dispatch(FormAction::Reset(values));
+    }
+
+    // Changed since the form was created or last reset
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.state.values.get(name) != self.state.initial.get(name)
+    }
+
+    pub fn is_form_dirty(&self) -> bool {
+        self.state.values != self.state.initial
+    }
+
+    pub fn errors(&self) -> FormErrors {
+        (self.validate)(&self.state.values)
+    }
+
+    // Errors stay hidden until the user has left the field or tried to
+    // submit, so nobody is told off halfway through typing an email. From
+    // then on they follow every keystroke
+    pub fn error(&self, name: &str) -> Option<String> {
+        if !self.state.submitted && !self.state.touched.contains(name) {
+            return None;
+        }
+        self.errors().remove(name)
+    }
+
+    // Reveals every error; returns the values only when the form is valid
+    pub fn submit(&self) -> Option<FormValues> {
+        self.state.dispatch(FormAction::Submit);
+        self.errors().is_empty().then(|| self.values())
+    }
+}
+
+pub fn add_error(errors: &mut FormErrors, name: &'static str, error: Option<String>) {
+    if let Some(error) = error {
+        errors.insert(name, error);
+    }
+}
+
+// "a", "a y b", "a, b y c"
+fn join_list(items: &[&str]) -> String {
+    match items.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!(
+            "{} {} {}",
+            rest.join(", "),
+            i18n::messages().validation.and,
+            last
+        ),
+        None => String::new(),
+    }
+}
+
+// Field rules shared by every form that collects the same data
+
+pub fn required_error(value: &str) -> Option<String> {
+    value
+        .trim()
+        .is_empty()
+        .then(|| i18n::messages().validation.required.to_string())
+}
+
+// Selects of a record may hold a value outside `T::ALL` (e.g. `Role::Unknown`)
+pub fn choice_error<T: Choice>(value: &str) -> Option<String> {
+    T::from_key(value)
+        .is_none()
+        .then(|| i18n::messages().validation.unknown_choice.to_string())
+}
+
+pub fn email_error(value: &str) -> Option<String> {
+    required_error(value).or_else(|| {
+        (!validate_email(value)).then(|| i18n::messages().validation.invalid_email.to_string())
+    })
+}
+
+pub fn password_error(value: &str) -> Option<String> {
+    let issues = password_issues(value);
+    (!issues.is_empty()).then(|| (i18n::messages().validation.password_needs)(&join_list(&issues)))
+}
+
+pub fn repeated_password_error(password: &str, repeated: &str) -> Option<String> {
+    (password != repeated).then(|| i18n::messages().validation.passwords_differ.to_string())
+}
+
+// Empty means the default of 0 hours
+pub fn hours_error(value: &str) -> Option<String> {
+    Hours::parse(value).err()
+}
+
+#[hook]
+pub fn use_form<F>(init: F, validate: Validator) -> UseForm
+where
+    F: FnOnce() -> FormValues,
+{
+    let state = use_reducer(move || {
+        let values = init();
+        FormState {
+            initial: values.clone(),
+            values,
+            touched: HashSet::new(),
+            submitted: false,
+        }
+    });
+
+    UseForm { state, validate }
+}
@@ -0,0 +1,289 @@
+use super::*;
+
+pub static MESSAGES: Messages = Messages {
+    decimal_separator: '.',
+    twelve_hour_clock: true,
+    common: CommonMessages {
+        yes: "Yes",
+        no: "No",
+        show: "Show",
+        hide: "Hide",
+        checking_session: "Checking your session...",
+        duplicate_user: "A user with that email or ID already exists",
+    },
+    navbar: NavbarMessages {
+        home: "Home",
+        profile: "Profile",
+        certificates: "Certificates",
+        admin: "Admin",
+        logout: "Log out",
+        register: "Sign up",
+        login: "Log in",
+        language: "Language",
+    },
+    home: HomeMessages {
+        speakers_title: "Featured Speakers",
+        location_title: "Venue",
+        modality_label: "Format:",
+        date_title: "Date and Times",
+        date_label: "Date: ",
+        start_label: "Starts: ",
+        end_label: "Ends: ",
+        schedule_title: "Schedule Overview:",
+        contact_title: "Contact Information",
+        email_label: "Email:",
+        organizers_title: "Organizers",
+        copyright: "2025 Revista Universidad y Salud. All rights reserved.",
+    },
+    fields: FieldMessages {
+        email: "Email:",
+        full_name: "Full name:",
+        identification: "ID document:",
+        password: "Password:",
+        repeat_password: "Repeat password:",
+        role: "Role:",
+        hours: "Presentation hours (0 to 40, in half hours; 0 by default):",
+        attendance: "Attendance:",
+    },
+    columns: ColumnMessages {
+        id: "SID",
+        email: "Email",
+        name: "Name",
+        identification: "ID",
+        role: "Role",
+        hours: "Hours",
+        attendance: "Attendance",
+        certificates: "Certificates generated",
+        horizontal: "Horizontal",
+        vertical: "Vertical",
+        horizontal_certificate: "Horizontal certificate",
+        vertical_certificate: "Vertical certificate",
+        actions: "Actions",
+        line: "Line",
+        status: "Status",
+    },
+    roles: RoleMessages {
+        attendee: "Attendee",
+        speaker: "Speaker",
+        staff: "Staff",
+        webmaster: "Administrator",
+        unknown: "Unknown",
+    },
+    attendance: AttendanceMessages {
+        remote: "Remote",
+        presential: "In person",
+        unknown: "Unknown",
+    },
+    validation: ValidationMessages {
+        required: "This field is required",
+        invalid_email: "Enter a valid email, for example name@domain.com",
+        passwords_differ: "Passwords do not match",
+        password_needs: |issues| format!("The password must have {}", issues),
+        and: "and",
+        password_length: "at least 8 characters",
+        password_lowercase: "a lowercase letter",
+        password_uppercase: "an uppercase letter",
+        password_digit: "a number",
+        password_special: "a special character (!@#$%^&*_=+')",
+        hours_not_number: "Hours must be a number, for example 2 or 1.5",
+        hours_negative: "Hours cannot be negative",
+        hours_too_many: |max| format!("Hours cannot exceed {}", max),
+        hours_not_half: "Use whole or half hours, for example 1.5",
+        unknown_choice: "Choose one of the options",
+    },
+    api: ApiMessages {
+        network: "Could not connect to the server. Please try again.",
+        serialization: "Unexpected response from the server.",
+        unauthorized: "Please log in to continue.",
+        forbidden: "You do not have permission to do this.",
+        not_found: "The requested resource does not exist.",
+        conflict: "Conflicts with existing data.",
+        conflict_with: |message| format!("Conflicts with existing data: {}", message),
+        invalid: "Invalid data.",
+        invalid_with: |message| format!("Invalid data: {}", message),
+        invalid_fields: |details| format!("Invalid data ({})", details),
+        server: |status| format!("Server error ({}). Please try again later.", status),
+    },
+    login: LoginMessages {
+        title: "Log in",
+        expired: "Your session has expired. Log in again to continue.",
+        wrong_credentials: "Incorrect email or password",
+        invalid_session: "Could not start the session: the server response is not valid. Please contact the organizers.",
+        submit: "Log in",
+    },
+    register: RegisterMessages {
+        title: "Sign up",
+        success: "User registered successfully",
+        submit: "Sign up",
+    },
+    profile: ProfileMessages {
+        title: "Profile",
+        loading: "Loading profile...",
+        load_error: |error| format!("Could not load the profile: {}", error),
+        no_changes: "There are no changes to save",
+        success: "Profile updated successfully",
+        duplicate_email: "Another user already has that email",
+        role: "Role: ",
+        hours: "Presentation hours: ",
+        certificates: "Certificates generated: ",
+        certificate_summary: |horizontal, vertical| {
+            format!("horizontal: {}, vertical: {}", horizontal, vertical)
+        },
+        submit: "Update",
+    },
+    change_password: ChangePasswordMessages {
+        title: "Change password",
+        current: "Current password:",
+        new: "New password:",
+        repeat: "Repeat the new password:",
+        must_differ: "The new password must be different from the current one",
+        success: "Password updated successfully",
+        wrong_current: "The current password is incorrect",
+    },
+    certificates: CertificatesMessages {
+        title: "Download certificates",
+        loading: "Loading certificates...",
+        load_error: |error| format!("Could not load your certificates: {}", error),
+        not_eligible: "You do not meet the requirements for this certificate yet",
+        open_error: "Could not open the certificate",
+        pending_notice: "Certificates will be available once the organizers confirm your attendance.",
+        certified_hours: "Certified presentation hours: ",
+        horizontal: "Horizontal certificate",
+        vertical: "Vertical certificate",
+        example_alt: |label| format!("Sample {}", label.to_lowercase()),
+        unavailable: "Not available yet",
+        available: "Available",
+        generated: "Already generated",
+        download: "Download",
+        generate: "Generate and download",
+        download_again: "Download again",
+        preview: "Preview",
+        preview_title: "Certificate preview",
+        close_preview: "Close preview",
+    },
+    admin_panel: AdminPanelMessages {
+        title: "Manage users",
+        register: "Register",
+        import: "Import CSV",
+        reload: "Refresh list",
+        export_csv: "Export CSV",
+        export_excel: "Export for Excel",
+        export_error: "Could not export the list",
+        export_filename: "users",
+        showing: |shown, total| format!("Showing {} of {} users", shown, total),
+        select_all: "Select all",
+        select_user: |name| format!("Select {}", name),
+        loading: "Loading users...",
+        empty: "There are no users yet",
+        no_matches: "No users match the filters",
+        edit: "Edit",
+        delete: "Delete",
+        delete_confirm: |name| format!("Are you sure you want to delete the user {}?", name),
+        already_deleted: "The user had already been deleted",
+        delete_conflict: "The user cannot be deleted because it has related records",
+        delete_error: |error| format!("Could not delete the user: {}", error),
+        deleted: |name| format!("User {} deleted.", name),
+        undo: "Undo",
+        restored: |name| format!("User {} restored", name),
+        restore_error: |error| format!("Could not restore the user: {}", error),
+    },
+    admin_register: AdminRegisterMessages {
+        title: "Register users",
+        fixture: "Fill with test data",
+        generate_password: "Generate password",
+        generate_error: "Could not generate a password",
+        generated_before: "Generated password: ",
+        generated_after: ". Give it to the user now; it will not be shown again.",
+        success_with_password: |password| {
+            format!(
+                "User registered successfully. Assigned password: {}",
+                password
+            )
+        },
+    },
+    admin_update: AdminUpdateMessages {
+        title: "Edit User",
+        loading: "Loading user data...",
+        load_error: |error| format!("Could not load the user: {}", error),
+        not_found: "The user does not exist",
+        duplicate: "Another user already has that email or ID",
+        changed_on_server: "This user changed on the server while you were editing. Saving will overwrite those changes; cancel to see the current version.",
+        identification: "ID:",
+        new_password: "New password (leave empty to keep it):",
+        hours: "Presentation hours (0 to 40, in half hours):",
+        save: "Save changes",
+        cancel: "Cancel",
+    },
+    admin_import: AdminImportMessages {
+        title: "Import users",
+        instructions: "Upload a CSV file with the columns: email, name, identification, password, \
+                       role (attendee, speaker or staff), hours and attendance (remote or presential).",
+        file_label: "CSV file:",
+        read_error: "Could not read the file",
+        empty_file: "The file is empty",
+        missing_columns: |columns| format!("Missing required columns: {}", columns),
+        invalid_email: "Invalid email",
+        repeated_email: "Email repeated in the file",
+        missing_name: "Missing name",
+        missing_identification: "Missing ID",
+        unknown_role: |role| format!("Unknown role: {}", role),
+        unknown_attendance: |attendance| format!("Unknown attendance: {}", attendance),
+        duplicate_email: "A user with that email already exists",
+        summary: |valid, rows, imported| {
+            format!(
+                "{} of {} rows are valid. {} imported.",
+                valid, rows, imported
+            )
+        },
+        finished: |created, failed| {
+            format!(
+                "Import finished: {} created, {} failed",
+                created, failed
+            )
+        },
+        created: "Created",
+        ready: "Ready",
+        submit: |count| format!("Import {} users", count),
+        back: "Back",
+    },
+    bulk: BulkMessages {
+        delete: "Delete",
+        reset_certificates: "Allow generating certificates again",
+        selected: |count| format!("{} selected", count),
+        action_label: "Bulk action",
+        choose: "Choose an action...",
+        apply: "Apply",
+        confirm: |action, count| format!("Apply \"{}\" to {} users?", action, count),
+        summary: |succeeded, failed| format!("{} succeeded, {} failed", succeeded, failed),
+        unknown_values: "The user has an unrecognized role or attendance; edit them individually",
+        ok: "OK",
+        error: "Error",
+        close: "Close summary",
+    },
+    filters: FilterMessages {
+        search_placeholder: "Search by name, email or ID",
+        search_label: "Search users",
+        role_label: "Filter by role",
+        all_roles: "All roles",
+        attendance_label: "Filter by attendance",
+        all_attendance: "Any attendance",
+        certificates_label: "Filter by certificates",
+        all_certificates: "Any certificates",
+        no_certificates: "No certificates",
+        one_certificate: "One certificate",
+        both_certificates: "Both certificates",
+        clear: "Clear filters",
+    },
+    pager: PagerMessages {
+        label: "Pagination",
+        previous: "Previous",
+        next: "Next",
+    },
+    errors: ErrorPageMessages {
+        forbidden_title: "Access denied",
+        forbidden_text: "You do not have permission to see this page.",
+        not_found_title: "Page not found",
+        not_found_text: "Sorry, the page you are looking for does not exist.",
+        back_home: "Back to home",
+    },
+};
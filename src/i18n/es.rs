@@ -0,0 +1,289 @@
+use super::*;
+
+pub static MESSAGES: Messages = Messages {
+    decimal_separator: ',',
+    twelve_hour_clock: true,
+    common: CommonMessages {
+        yes: "Sí",
+        no: "No",
+        show: "Mostrar",
+        hide: "Ocultar",
+        checking_session: "Verificando autenticación...",
+        duplicate_user: "Ya existe un usuario con ese correo o identificación",
+    },
+    navbar: NavbarMessages {
+        home: "Inicio",
+        profile: "Perfil",
+        certificates: "Certificados",
+        admin: "Admin",
+        logout: "Cerrar Sesión",
+        register: "Registro",
+        login: "Ingresar",
+        language: "Idioma",
+    },
+    home: HomeMessages {
+        speakers_title: "Ponentes Destacados",
+        location_title: "Ubicación del Evento",
+        modality_label: "Modalidad:",
+        date_title: "Fecha y Horarios",
+        date_label: "Fecha: ",
+        start_label: "Hora de inicio: ",
+        end_label: "Hora de finalización: ",
+        schedule_title: "Agenda Resumida:",
+        contact_title: "Información de Contacto",
+        email_label: "Email:",
+        organizers_title: "Organizadores",
+        copyright: "2025 Revista Universidad y Salud. Todos los derechos reservados.",
+    },
+    fields: FieldMessages {
+        email: "Correo electrónico:",
+        full_name: "Nombre completo:",
+        identification: "Documento de identificación:",
+        password: "Contraseña:",
+        repeat_password: "Repita contraseña:",
+        role: "Rol:",
+        hours: "Horas de la ponencia (0 a 40, en medias horas; 0 por defecto):",
+        attendance: "Tipo de asistencia:",
+    },
+    columns: ColumnMessages {
+        id: "SID",
+        email: "E-mail",
+        name: "Nombre",
+        identification: "Identificación",
+        role: "Rol",
+        hours: "Horas",
+        attendance: "Asistencia",
+        certificates: "Constancias generadas",
+        horizontal: "Horizontal",
+        vertical: "Vertical",
+        horizontal_certificate: "Constancia horizontal",
+        vertical_certificate: "Constancia vertical",
+        actions: "Acciones",
+        line: "Línea",
+        status: "Estado",
+    },
+    roles: RoleMessages {
+        attendee: "Asistente",
+        speaker: "Ponente",
+        staff: "Organizador",
+        webmaster: "Administrador",
+        unknown: "Desconocido",
+    },
+    attendance: AttendanceMessages {
+        remote: "Remota",
+        presential: "Presencial",
+        unknown: "Desconocida",
+    },
+    validation: ValidationMessages {
+        required: "Este campo es obligatorio",
+        invalid_email: "Ingrese un correo válido, por ejemplo nombre@dominio.com",
+        passwords_differ: "Las contraseñas no coinciden",
+        password_needs: |issues| format!("La contraseña debe tener {}", issues),
+        and: "y",
+        password_length: "al menos 8 caracteres",
+        password_lowercase: "una letra minúscula",
+        password_uppercase: "una letra mayúscula",
+        password_digit: "un número",
+        password_special: "un carácter especial (!@#$%^&*_=+')",
+        hours_not_number: "Las horas deben ser un número, por ejemplo 2 o 1,5",
+        hours_negative: "Las horas no pueden ser negativas",
+        hours_too_many: |max| format!("Las horas no pueden superar {}", max),
+        hours_not_half: "Use horas completas o medias horas, por ejemplo 1,5",
+        unknown_choice: "Elija una de las opciones",
+    },
+    api: ApiMessages {
+        network: "No fue posible conectar con el servidor. Intente de nuevo.",
+        serialization: "Respuesta inesperada del servidor.",
+        unauthorized: "Debe iniciar sesión para continuar.",
+        forbidden: "No tiene permisos para realizar esta acción.",
+        not_found: "El recurso solicitado no existe.",
+        conflict: "Conflicto con los datos existentes.",
+        conflict_with: |message| format!("Conflicto con los datos existentes: {}", message),
+        invalid: "Datos no válidos.",
+        invalid_with: |message| format!("Datos no válidos: {}", message),
+        invalid_fields: |details| format!("Datos no válidos ({})", details),
+        server: |status| format!("Error del servidor ({}). Intente más tarde.", status),
+    },
+    login: LoginMessages {
+        title: "Ingreso",
+        expired: "Su sesión ha expirado. Ingrese nuevamente para continuar.",
+        wrong_credentials: "Correo o contraseña incorrectos",
+        invalid_session: "No fue posible iniciar la sesión: la respuesta del servidor no es válida. Contacte a los organizadores.",
+        submit: "Ingresar",
+    },
+    register: RegisterMessages {
+        title: "Registro",
+        success: "Usuario registrado exitosamente",
+        submit: "Registrarse",
+    },
+    profile: ProfileMessages {
+        title: "Perfil",
+        loading: "Cargando perfil...",
+        load_error: |error| format!("Error al cargar el perfil: {}", error),
+        no_changes: "No hay cambios para guardar",
+        success: "Perfil actualizado exitosamente",
+        duplicate_email: "Ya existe otro usuario con ese correo",
+        role: "Rol: ",
+        hours: "Horas de ponencia: ",
+        certificates: "Constancias generadas: ",
+        certificate_summary: |horizontal, vertical| {
+            format!("horizontal: {}, vertical: {}", horizontal, vertical)
+        },
+        submit: "Actualizar",
+    },
+    change_password: ChangePasswordMessages {
+        title: "Cambiar contraseña",
+        current: "Contraseña actual:",
+        new: "Nueva contraseña:",
+        repeat: "Repita la nueva contraseña:",
+        must_differ: "La nueva contraseña debe ser diferente a la actual",
+        success: "Contraseña actualizada exitosamente",
+        wrong_current: "La contraseña actual es incorrecta",
+    },
+    certificates: CertificatesMessages {
+        title: "Descargar certificados",
+        loading: "Cargando constancias...",
+        load_error: |error| format!("Error al cargar sus constancias: {}", error),
+        not_eligible: "Aún no cumple los requisitos para obtener esta constancia",
+        open_error: "No fue posible abrir la constancia",
+        pending_notice: "Las constancias estarán disponibles cuando los organizadores confirmen su asistencia.",
+        certified_hours: "Horas de ponencia certificadas: ",
+        horizontal: "Constancia horizontal",
+        vertical: "Constancia vertical",
+        example_alt: |label| format!("Ejemplo de {}", label.to_lowercase()),
+        unavailable: "No disponible todavía",
+        available: "Disponible",
+        generated: "Ya generada",
+        download: "Descargar",
+        generate: "Generar y descargar",
+        download_again: "Volver a descargar",
+        preview: "Vista previa",
+        preview_title: "Vista previa de la constancia",
+        close_preview: "Cerrar vista previa",
+    },
+    admin_panel: AdminPanelMessages {
+        title: "Administrar usuarios",
+        register: "Registrar",
+        import: "Importar CSV",
+        reload: "Actualizar lista",
+        export_csv: "Exportar CSV",
+        export_excel: "Exportar para Excel",
+        export_error: "No fue posible exportar la lista",
+        export_filename: "usuarios",
+        showing: |shown, total| format!("Mostrando {} de {} usuarios", shown, total),
+        select_all: "Seleccionar todos",
+        select_user: |name| format!("Seleccionar a {}", name),
+        loading: "Cargando usuarios...",
+        empty: "Todavía no hay usuarios",
+        no_matches: "Ningún usuario coincide con los filtros",
+        edit: "Editar",
+        delete: "Eliminar",
+        delete_confirm: |name| format!("¿Está seguro que desea eliminar al usuario {}?", name),
+        already_deleted: "El usuario ya había sido eliminado",
+        delete_conflict: "No se puede eliminar al usuario porque tiene registros asociados",
+        delete_error: |error| format!("Error al eliminar usuario: {}", error),
+        deleted: |name| format!("Usuario {} eliminado.", name),
+        undo: "Deshacer",
+        restored: |name| format!("Usuario {} restaurado", name),
+        restore_error: |error| format!("Error al restaurar usuario: {}", error),
+    },
+    admin_register: AdminRegisterMessages {
+        title: "Registro de usuarios",
+        fixture: "Rellenar con datos de prueba",
+        generate_password: "Generar contraseña",
+        generate_error: "No fue posible generar la contraseña",
+        generated_before: "Contraseña generada: ",
+        generated_after: ". Entréguela al usuario ahora; no se volverá a mostrar.",
+        success_with_password: |password| {
+            format!(
+                "Usuario registrado exitosamente. Contraseña asignada: {}",
+                password
+            )
+        },
+    },
+    admin_update: AdminUpdateMessages {
+        title: "Editar Usuario",
+        loading: "Cargando datos del usuario...",
+        load_error: |error| format!("Error al cargar el usuario: {}", error),
+        not_found: "El usuario no existe",
+        duplicate: "Ya existe otro usuario con ese correo o identificación",
+        changed_on_server: "Este usuario cambió en el servidor mientras lo editaba. Guardar sobrescribirá esos cambios; cancele para ver la versión actual.",
+        identification: "Identificación:",
+        new_password: "Nueva contraseña (dejar vacío para no cambiar):",
+        hours: "Horas de ponencia (0 a 40, en medias horas):",
+        save: "Guardar cambios",
+        cancel: "Cancelar",
+    },
+    admin_import: AdminImportMessages {
+        title: "Importar usuarios",
+        instructions: "Suba un archivo CSV con las columnas: correo, nombre, identificación, contraseña, \
+                       rol (asistente, ponente u organizador), horas y asistencia (remota o presencial).",
+        file_label: "Archivo CSV:",
+        read_error: "No fue posible leer el archivo",
+        empty_file: "El archivo está vacío",
+        missing_columns: |columns| format!("Faltan columnas obligatorias: {}", columns),
+        invalid_email: "Correo no válido",
+        repeated_email: "Correo repetido en el archivo",
+        missing_name: "Falta el nombre",
+        missing_identification: "Falta la identificación",
+        unknown_role: |role| format!("Rol desconocido: {}", role),
+        unknown_attendance: |attendance| format!("Asistencia desconocida: {}", attendance),
+        duplicate_email: "Ya existe un usuario con ese correo",
+        summary: |valid, rows, imported| {
+            format!(
+                "{} filas válidas de {}. {} importadas.",
+                valid, rows, imported
+            )
+        },
+        finished: |created, failed| {
+            format!(
+                "Importación terminada: {} creados, {} con error",
+                created, failed
+            )
+        },
+        created: "Creado",
+        ready: "Listo",
+        submit: |count| format!("Importar {} usuarios", count),
+        back: "Volver",
+    },
+    bulk: BulkMessages {
+        delete: "Eliminar",
+        reset_certificates: "Permitir regenerar constancias",
+        selected: |count| format!("{} seleccionados", count),
+        action_label: "Acción masiva",
+        choose: "Elegir acción...",
+        apply: "Aplicar",
+        confirm: |action, count| format!("¿Aplicar \"{}\" a {} usuarios?", action, count),
+        summary: |succeeded, failed| format!("{} correctos, {} con error", succeeded, failed),
+        unknown_values: "El usuario tiene un rol o una asistencia no reconocidos; edítelo individualmente",
+        ok: "OK",
+        error: "Error",
+        close: "Cerrar resumen",
+    },
+    filters: FilterMessages {
+        search_placeholder: "Buscar por nombre, correo o identificación",
+        search_label: "Buscar usuarios",
+        role_label: "Filtrar por rol",
+        all_roles: "Todos los roles",
+        attendance_label: "Filtrar por asistencia",
+        all_attendance: "Toda asistencia",
+        certificates_label: "Filtrar por constancias",
+        all_certificates: "Todas las constancias",
+        no_certificates: "Sin constancias",
+        one_certificate: "Una constancia",
+        both_certificates: "Ambas constancias",
+        clear: "Limpiar filtros",
+    },
+    pager: PagerMessages {
+        label: "Paginación",
+        previous: "Anterior",
+        next: "Siguiente",
+    },
+    errors: ErrorPageMessages {
+        forbidden_title: "Acceso denegado",
+        forbidden_text: "No tiene permisos para ver esta página.",
+        not_found_title: "Página no encontrada",
+        not_found_text: "Lo sentimos, la página que buscas no existe.",
+        back_home: "Volver al inicio",
+    },
+};
@@ -0,0 +1,437 @@
+mod en;
+mod es;
+mod pt;
+
+use std::cell::Cell;
+
+use gloo_storage::{LocalStorage, Storage};
+
+use crate::types::Choice;
+
+const LANGUAGE_KEY: &str = "language";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[default]
+    Es,
+    Pt,
+    En,
+}
+
+impl Choice for Language {
+    const ALL: &'static [Self] = &[Language::Es, Language::Pt, Language::En];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Language::Es => "es",
+            Language::Pt => "pt",
+            Language::En => "en",
+        }
+    }
+
+    // Each language is named in itself, so the switcher reads the same in
+    // every language
+    fn label(&self) -> &'static str {
+        match self {
+            Language::Es => "Español",
+            Language::Pt => "Português",
+            Language::En => "English",
+        }
+    }
+}
+
+impl Language {
+    pub fn messages(&self) -> &'static Messages {
+        match self {
+            Language::Es => &es::MESSAGES,
+            Language::Pt => &pt::MESSAGES,
+            Language::En => &en::MESSAGES,
+        }
+    }
+
+    // "pt-BR" -> Pt; unsupported languages give None
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next().unwrap_or_default();
+        Self::from_key(&primary.to_lowercase())
+    }
+
+    // The saved choice, otherwise the first supported browser language
+    pub fn initial() -> Self {
+        if let Some(language) = LocalStorage::get::<String>(LANGUAGE_KEY)
+            .ok()
+            .and_then(|key| Self::from_key(&key))
+        {
+            return language;
+        }
+
+        gloo_utils::window()
+            .navigator()
+            .languages()
+            .iter()
+            .filter_map(|tag| tag.as_string())
+            .find_map(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Language> = Cell::new(Language::default());
+}
+
+pub fn current() -> Language {
+    CURRENT.with(Cell::get)
+}
+
+// Messages of the current language, for code outside components (validation,
+// API errors, labels). Components use `use_i18n` so they re-render on change
+pub fn messages() -> &'static Messages {
+    current().messages()
+}
+
+// Makes `language` current and updates the document's lang attribute
+pub fn apply(language: Language) {
+    CURRENT.with(|current| current.set(language));
+    if let Some(root) = gloo_utils::document().document_element() {
+        let _ = root.set_attribute("lang", language.key());
+    }
+}
+
+pub fn save(language: Language) {
+    let _ = LocalStorage::set(LANGUAGE_KEY, language.key());
+}
+
+// Message catalogs, one `Messages` value per language in es.rs, pt.rs and
+// en.rs. Texts with values in them are functions
+
+pub struct Messages {
+    // Decimal separator for hours, e.g. "1,5" or "1.5"
+    pub decimal_separator: char,
+    // "2:00 PM" rather than "14:00"
+    pub twelve_hour_clock: bool,
+    pub common: CommonMessages,
+    pub navbar: NavbarMessages,
+    pub home: HomeMessages,
+    pub fields: FieldMessages,
+    pub columns: ColumnMessages,
+    pub roles: RoleMessages,
+    pub attendance: AttendanceMessages,
+    pub validation: ValidationMessages,
+    pub api: ApiMessages,
+    pub login: LoginMessages,
+    pub register: RegisterMessages,
+    pub profile: ProfileMessages,
+    pub change_password: ChangePasswordMessages,
+    pub certificates: CertificatesMessages,
+    pub admin_panel: AdminPanelMessages,
+    pub admin_register: AdminRegisterMessages,
+    pub admin_update: AdminUpdateMessages,
+    pub admin_import: AdminImportMessages,
+    pub bulk: BulkMessages,
+    pub filters: FilterMessages,
+    pub pager: PagerMessages,
+    pub errors: ErrorPageMessages,
+}
+
+pub struct CommonMessages {
+    pub yes: &'static str,
+    pub no: &'static str,
+    pub show: &'static str,
+    pub hide: &'static str,
+    pub checking_session: &'static str,
+    pub duplicate_user: &'static str,
+}
+
+pub struct NavbarMessages {
+    pub home: &'static str,
+    pub profile: &'static str,
+    pub certificates: &'static str,
+    pub admin: &'static str,
+    pub logout: &'static str,
+    pub register: &'static str,
+    pub login: &'static str,
+    pub language: &'static str,
+}
+
+// Labels of the Home page; the event details themselves come from
+// content/event.json
+pub struct HomeMessages {
+    pub speakers_title: &'static str,
+    pub location_title: &'static str,
+    pub modality_label: &'static str,
+    pub date_title: &'static str,
+    pub date_label: &'static str,
+    pub start_label: &'static str,
+    pub end_label: &'static str,
+    pub schedule_title: &'static str,
+    pub contact_title: &'static str,
+    pub email_label: &'static str,
+    pub organizers_title: &'static str,
+    pub copyright: &'static str,
+}
+
+// Form labels shared by the registration and profile forms
+pub struct FieldMessages {
+    pub email: &'static str,
+    pub full_name: &'static str,
+    pub identification: &'static str,
+    pub password: &'static str,
+    pub repeat_password: &'static str,
+    pub role: &'static str,
+    pub hours: &'static str,
+    pub attendance: &'static str,
+}
+
+// Table and CSV column headers
+pub struct ColumnMessages {
+    pub id: &'static str,
+    pub email: &'static str,
+    pub name: &'static str,
+    pub identification: &'static str,
+    pub role: &'static str,
+    pub hours: &'static str,
+    pub attendance: &'static str,
+    pub certificates: &'static str,
+    pub horizontal: &'static str,
+    pub vertical: &'static str,
+    pub horizontal_certificate: &'static str,
+    pub vertical_certificate: &'static str,
+    pub actions: &'static str,
+    pub line: &'static str,
+    pub status: &'static str,
+}
+
+pub struct RoleMessages {
+    pub attendee: &'static str,
+    pub speaker: &'static str,
+    pub staff: &'static str,
+    pub webmaster: &'static str,
+    // A role this version does not know about
+    pub unknown: &'static str,
+}
+
+pub struct AttendanceMessages {
+    pub remote: &'static str,
+    pub presential: &'static str,
+    pub unknown: &'static str,
+}
+
+pub struct ValidationMessages {
+    pub required: &'static str,
+    pub invalid_email: &'static str,
+    pub passwords_differ: &'static str,
+    // Completed with a list of `password_*` items
+    pub password_needs: fn(&str) -> String,
+    // Joins the last two items of a list
+    pub and: &'static str,
+    pub password_length: &'static str,
+    pub password_lowercase: &'static str,
+    pub password_uppercase: &'static str,
+    pub password_digit: &'static str,
+    pub password_special: &'static str,
+    pub hours_not_number: &'static str,
+    pub hours_negative: &'static str,
+    pub hours_too_many: fn(&str) -> String,
+    pub hours_not_half: &'static str,
+    // A select still holding a value this version does not know
+    pub unknown_choice: &'static str,
+}
+
+pub struct ApiMessages {
+    pub network: &'static str,
+    pub serialization: &'static str,
+    pub unauthorized: &'static str,
+    pub forbidden: &'static str,
+    pub not_found: &'static str,
+    pub conflict: &'static str,
+    pub conflict_with: fn(&str) -> String,
+    pub invalid: &'static str,
+    pub invalid_with: fn(&str) -> String,
+    pub invalid_fields: fn(&str) -> String,
+    pub server: fn(u16) -> String,
+}
+
+pub struct LoginMessages {
+    pub title: &'static str,
+    pub expired: &'static str,
+    pub wrong_credentials: &'static str,
+    // The server accepted the credentials but its token could not be read
+    pub invalid_session: &'static str,
+    pub submit: &'static str,
+}
+
+pub struct RegisterMessages {
+    pub title: &'static str,
+    pub success: &'static str,
+    pub submit: &'static str,
+}
+
+pub struct ProfileMessages {
+    pub title: &'static str,
+    pub loading: &'static str,
+    pub load_error: fn(&str) -> String,
+    pub no_changes: &'static str,
+    pub success: &'static str,
+    pub duplicate_email: &'static str,
+    pub role: &'static str,
+    pub hours: &'static str,
+    pub certificates: &'static str,
+    pub certificate_summary: fn(&str, &str) -> String,
+    pub submit: &'static str,
+}
+
+pub struct ChangePasswordMessages {
+    pub title: &'static str,
+    pub current: &'static str,
+    pub new: &'static str,
+    pub repeat: &'static str,
+    pub must_differ: &'static str,
+    pub success: &'static str,
+    pub wrong_current: &'static str,
+}
+
+pub struct CertificatesMessages {
+    pub title: &'static str,
+    pub loading: &'static str,
+    pub load_error: fn(&str) -> String,
+    pub not_eligible: &'static str,
+    pub open_error: &'static str,
+    pub pending_notice: &'static str,
+    pub certified_hours: &'static str,
+    pub horizontal: &'static str,
+    pub vertical: &'static str,
+    pub example_alt: fn(&str) -> String,
+    pub unavailable: &'static str,
+    pub available: &'static str,
+    pub generated: &'static str,
+    pub download: &'static str,
+    pub generate: &'static str,
+    pub download_again: &'static str,
+    pub preview: &'static str,
+    pub preview_title: &'static str,
+    pub close_preview: &'static str,
+}
+
+pub struct AdminPanelMessages {
+    pub title: &'static str,
+    pub register: &'static str,
+    pub import: &'static str,
+    pub reload: &'static str,
+    pub export_csv: &'static str,
+    pub export_excel: &'static str,
+    pub export_error: &'static str,
+    // Name of exported files, before the date
+    pub export_filename: &'static str,
+    pub showing: fn(usize, u64) -> String,
+    pub select_all: &'static str,
+    pub select_user: fn(&str) -> String,
+    pub loading: &'static str,
+    pub empty: &'static str,
+    pub no_matches: &'static str,
+    pub edit: &'static str,
+    pub delete: &'static str,
+    pub delete_confirm: fn(&str) -> String,
+    pub already_deleted: &'static str,
+    pub delete_conflict: &'static str,
+    pub delete_error: fn(&str) -> String,
+    pub deleted: fn(&str) -> String,
+    pub undo: &'static str,
+    pub restored: fn(&str) -> String,
+    pub restore_error: fn(&str) -> String,
+}
+
+pub struct AdminRegisterMessages {
+    pub title: &'static str,
+    // Only shown with the dev-fixtures feature
+    #[cfg_attr(not(feature = "dev-fixtures"), allow(dead_code))]
+    pub fixture: &'static str,
+    pub generate_password: &'static str,
+    pub generate_error: &'static str,
+    // Around the generated password
+    pub generated_before: &'static str,
+    pub generated_after: &'static str,
+    pub success_with_password: fn(&str) -> String,
+}
+
+pub struct AdminUpdateMessages {
+    pub title: &'static str,
+    pub loading: &'static str,
+    pub load_error: fn(&str) -> String,
+    pub not_found: &'static str,
+    pub duplicate: &'static str,
+    // The record changed on the server while the admin was editing it
+    pub changed_on_server: &'static str,
+    pub identification: &'static str,
+    pub new_password: &'static str,
+    pub hours: &'static str,
+    pub save: &'static str,
+    pub cancel: &'static str,
+}
+
+pub struct AdminImportMessages {
+    pub title: &'static str,
+    pub instructions: &'static str,
+    pub file_label: &'static str,
+    pub read_error: &'static str,
+    pub empty_file: &'static str,
+    pub missing_columns: fn(&str) -> String,
+    pub invalid_email: &'static str,
+    pub repeated_email: &'static str,
+    pub missing_name: &'static str,
+    pub missing_identification: &'static str,
+    pub unknown_role: fn(&str) -> String,
+    pub unknown_attendance: fn(&str) -> String,
+    pub duplicate_email: &'static str,
+    // (valid rows, rows, imported)
+    pub summary: fn(usize, usize, usize) -> String,
+    // (created, failed)
+    pub finished: fn(usize, usize) -> String,
+    pub created: &'static str,
+    pub ready: &'static str,
+    pub submit: fn(usize) -> String,
+    pub back: &'static str,
+}
+
+pub struct BulkMessages {
+    pub delete: &'static str,
+    pub reset_certificates: &'static str,
+    pub selected: fn(usize) -> String,
+    pub action_label: &'static str,
+    pub choose: &'static str,
+    pub apply: &'static str,
+    pub confirm: fn(&str, usize) -> String,
+    // (succeeded, failed)
+    pub summary: fn(usize, usize) -> String,
+    pub unknown_values: &'static str,
+    pub ok: &'static str,
+    pub error: &'static str,
+    pub close: &'static str,
+}
+
+pub struct FilterMessages {
+    pub search_placeholder: &'static str,
+    pub search_label: &'static str,
+    pub role_label: &'static str,
+    pub all_roles: &'static str,
+    pub attendance_label: &'static str,
+    pub all_attendance: &'static str,
+    pub certificates_label: &'static str,
+    pub all_certificates: &'static str,
+    pub no_certificates: &'static str,
+    pub one_certificate: &'static str,
+    pub both_certificates: &'static str,
+    pub clear: &'static str,
+}
+
+pub struct PagerMessages {
+    pub label: &'static str,
+    pub previous: &'static str,
+    pub next: &'static str,
+}
+
+pub struct ErrorPageMessages {
+    pub forbidden_title: &'static str,
+    pub forbidden_text: &'static str,
+    pub not_found_title: &'static str,
+    pub not_found_text: &'static str,
+    pub back_home: &'static str,
+}
@@ -0,0 +1,289 @@
+use super::*;
+
+pub static MESSAGES: Messages = Messages {
+    decimal_separator: ',',
+    twelve_hour_clock: false,
+    common: CommonMessages {
+        yes: "Sim",
+        no: "Não",
+        show: "Mostrar",
+        hide: "Ocultar",
+        checking_session: "Verificando autenticação...",
+        duplicate_user: "Já existe um usuário com esse e-mail ou documento",
+    },
+    navbar: NavbarMessages {
+        home: "Início",
+        profile: "Perfil",
+        certificates: "Certificados",
+        admin: "Admin",
+        logout: "Sair",
+        register: "Inscrição",
+        login: "Entrar",
+        language: "Idioma",
+    },
+    home: HomeMessages {
+        speakers_title: "Palestrantes em Destaque",
+        location_title: "Local do Evento",
+        modality_label: "Modalidade:",
+        date_title: "Data e Horários",
+        date_label: "Data: ",
+        start_label: "Início: ",
+        end_label: "Término: ",
+        schedule_title: "Programação Resumida:",
+        contact_title: "Informações de Contato",
+        email_label: "E-mail:",
+        organizers_title: "Organizadores",
+        copyright: "2025 Revista Universidad y Salud. Todos os direitos reservados.",
+    },
+    fields: FieldMessages {
+        email: "E-mail:",
+        full_name: "Nome completo:",
+        identification: "Documento de identificação:",
+        password: "Senha:",
+        repeat_password: "Repita a senha:",
+        role: "Função:",
+        hours: "Horas da palestra (0 a 40, em meias horas; 0 por padrão):",
+        attendance: "Tipo de participação:",
+    },
+    columns: ColumnMessages {
+        id: "SID",
+        email: "E-mail",
+        name: "Nome",
+        identification: "Documento",
+        role: "Função",
+        hours: "Horas",
+        attendance: "Participação",
+        certificates: "Certificados gerados",
+        horizontal: "Horizontal",
+        vertical: "Vertical",
+        horizontal_certificate: "Certificado horizontal",
+        vertical_certificate: "Certificado vertical",
+        actions: "Ações",
+        line: "Linha",
+        status: "Situação",
+    },
+    roles: RoleMessages {
+        attendee: "Participante",
+        speaker: "Palestrante",
+        staff: "Organizador",
+        webmaster: "Administrador",
+        unknown: "Desconhecida",
+    },
+    attendance: AttendanceMessages {
+        remote: "Remota",
+        presential: "Presencial",
+        unknown: "Desconhecida",
+    },
+    validation: ValidationMessages {
+        required: "Este campo é obrigatório",
+        invalid_email: "Informe um e-mail válido, por exemplo nome@dominio.com",
+        passwords_differ: "As senhas não coincidem",
+        password_needs: |issues| format!("A senha deve ter {}", issues),
+        and: "e",
+        password_length: "pelo menos 8 caracteres",
+        password_lowercase: "uma letra minúscula",
+        password_uppercase: "uma letra maiúscula",
+        password_digit: "um número",
+        password_special: "um caractere especial (!@#$%^&*_=+')",
+        hours_not_number: "As horas devem ser um número, por exemplo 2 ou 1,5",
+        hours_negative: "As horas não podem ser negativas",
+        hours_too_many: |max| format!("As horas não podem passar de {}", max),
+        hours_not_half: "Use horas inteiras ou meias horas, por exemplo 1,5",
+        unknown_choice: "Escolha uma das opções",
+    },
+    api: ApiMessages {
+        network: "Não foi possível conectar ao servidor. Tente novamente.",
+        serialization: "Resposta inesperada do servidor.",
+        unauthorized: "Entre na sua conta para continuar.",
+        forbidden: "Você não tem permissão para realizar esta ação.",
+        not_found: "O recurso solicitado não existe.",
+        conflict: "Conflito com os dados existentes.",
+        conflict_with: |message| format!("Conflito com os dados existentes: {}", message),
+        invalid: "Dados inválidos.",
+        invalid_with: |message| format!("Dados inválidos: {}", message),
+        invalid_fields: |details| format!("Dados inválidos ({})", details),
+        server: |status| format!("Erro do servidor ({}). Tente mais tarde.", status),
+    },
+    login: LoginMessages {
+        title: "Entrar",
+        expired: "Sua sessão expirou. Entre novamente para continuar.",
+        wrong_credentials: "E-mail ou senha incorretos",
+        invalid_session: "Não foi possível iniciar a sessão: a resposta do servidor não é válida. Entre em contato com os organizadores.",
+        submit: "Entrar",
+    },
+    register: RegisterMessages {
+        title: "Inscrição",
+        success: "Usuário cadastrado com sucesso",
+        submit: "Inscrever-se",
+    },
+    profile: ProfileMessages {
+        title: "Perfil",
+        loading: "Carregando perfil...",
+        load_error: |error| format!("Erro ao carregar o perfil: {}", error),
+        no_changes: "Não há alterações para salvar",
+        success: "Perfil atualizado com sucesso",
+        duplicate_email: "Já existe outro usuário com esse e-mail",
+        role: "Função: ",
+        hours: "Horas de palestra: ",
+        certificates: "Certificados gerados: ",
+        certificate_summary: |horizontal, vertical| {
+            format!("horizontal: {}, vertical: {}", horizontal, vertical)
+        },
+        submit: "Atualizar",
+    },
+    change_password: ChangePasswordMessages {
+        title: "Alterar senha",
+        current: "Senha atual:",
+        new: "Nova senha:",
+        repeat: "Repita a nova senha:",
+        must_differ: "A nova senha deve ser diferente da atual",
+        success: "Senha atualizada com sucesso",
+        wrong_current: "A senha atual está incorreta",
+    },
+    certificates: CertificatesMessages {
+        title: "Baixar certificados",
+        loading: "Carregando certificados...",
+        load_error: |error| format!("Erro ao carregar seus certificados: {}", error),
+        not_eligible: "Você ainda não cumpre os requisitos para obter este certificado",
+        open_error: "Não foi possível abrir o certificado",
+        pending_notice: "Os certificados estarão disponíveis quando os organizadores confirmarem sua participação.",
+        certified_hours: "Horas de palestra certificadas: ",
+        horizontal: "Certificado horizontal",
+        vertical: "Certificado vertical",
+        example_alt: |label| format!("Exemplo de {}", label.to_lowercase()),
+        unavailable: "Ainda não disponível",
+        available: "Disponível",
+        generated: "Já gerado",
+        download: "Baixar",
+        generate: "Gerar e baixar",
+        download_again: "Baixar novamente",
+        preview: "Pré-visualizar",
+        preview_title: "Pré-visualização do certificado",
+        close_preview: "Fechar pré-visualização",
+    },
+    admin_panel: AdminPanelMessages {
+        title: "Administrar usuários",
+        register: "Cadastrar",
+        import: "Importar CSV",
+        reload: "Atualizar lista",
+        export_csv: "Exportar CSV",
+        export_excel: "Exportar para Excel",
+        export_error: "Não foi possível exportar a lista",
+        export_filename: "usuarios",
+        showing: |shown, total| format!("Mostrando {} de {} usuários", shown, total),
+        select_all: "Selecionar todos",
+        select_user: |name| format!("Selecionar {}", name),
+        loading: "Carregando usuários...",
+        empty: "Ainda não há usuários",
+        no_matches: "Nenhum usuário corresponde aos filtros",
+        edit: "Editar",
+        delete: "Excluir",
+        delete_confirm: |name| format!("Tem certeza de que deseja excluir o usuário {}?", name),
+        already_deleted: "O usuário já havia sido excluído",
+        delete_conflict: "Não é possível excluir o usuário porque ele tem registros associados",
+        delete_error: |error| format!("Erro ao excluir usuário: {}", error),
+        deleted: |name| format!("Usuário {} excluído.", name),
+        undo: "Desfazer",
+        restored: |name| format!("Usuário {} restaurado", name),
+        restore_error: |error| format!("Erro ao restaurar usuário: {}", error),
+    },
+    admin_register: AdminRegisterMessages {
+        title: "Cadastro de usuários",
+        fixture: "Preencher com dados de teste",
+        generate_password: "Gerar senha",
+        generate_error: "Não foi possível gerar a senha",
+        generated_before: "Senha gerada: ",
+        generated_after: ". Entregue-a ao usuário agora; ela não será mostrada novamente.",
+        success_with_password: |password| {
+            format!(
+                "Usuário cadastrado com sucesso. Senha atribuída: {}",
+                password
+            )
+        },
+    },
+    admin_update: AdminUpdateMessages {
+        title: "Editar Usuário",
+        loading: "Carregando dados do usuário...",
+        load_error: |error| format!("Erro ao carregar o usuário: {}", error),
+        not_found: "O usuário não existe",
+        duplicate: "Já existe outro usuário com esse e-mail ou documento",
+        changed_on_server: "Este usuário foi alterado no servidor enquanto você o editava. Salvar substituirá essas alterações; cancele para ver a versão atual.",
+        identification: "Documento:",
+        new_password: "Nova senha (deixe vazio para não alterar):",
+        hours: "Horas de palestra (0 a 40, em meias horas):",
+        save: "Salvar alterações",
+        cancel: "Cancelar",
+    },
+    admin_import: AdminImportMessages {
+        title: "Importar usuários",
+        instructions: "Envie um arquivo CSV com as colunas: e-mail, nome, documento, senha, \
+                       função (participante, palestrante ou organizador), horas e participação (remota ou presencial).",
+        file_label: "Arquivo CSV:",
+        read_error: "Não foi possível ler o arquivo",
+        empty_file: "O arquivo está vazio",
+        missing_columns: |columns| format!("Faltam colunas obrigatórias: {}", columns),
+        invalid_email: "E-mail inválido",
+        repeated_email: "E-mail repetido no arquivo",
+        missing_name: "Falta o nome",
+        missing_identification: "Falta o documento",
+        unknown_role: |role| format!("Função desconhecida: {}", role),
+        unknown_attendance: |attendance| format!("Participação desconhecida: {}", attendance),
+        duplicate_email: "Já existe um usuário com esse e-mail",
+        summary: |valid, rows, imported| {
+            format!(
+                "{} linhas válidas de {}. {} importadas.",
+                valid, rows, imported
+            )
+        },
+        finished: |created, failed| {
+            format!(
+                "Importação concluída: {} criados, {} com erro",
+                created, failed
+            )
+        },
+        created: "Criado",
+        ready: "Pronto",
+        submit: |count| format!("Importar {} usuários", count),
+        back: "Voltar",
+    },
+    bulk: BulkMessages {
+        delete: "Excluir",
+        reset_certificates: "Permitir gerar certificados novamente",
+        selected: |count| format!("{} selecionados", count),
+        action_label: "Ação em massa",
+        choose: "Escolher ação...",
+        apply: "Aplicar",
+        confirm: |action, count| format!("Aplicar \"{}\" a {} usuários?", action, count),
+        summary: |succeeded, failed| format!("{} com sucesso, {} com erro", succeeded, failed),
+        unknown_values: "O usuário tem uma função ou participação não reconhecida; edite-o individualmente",
+        ok: "OK",
+        error: "Erro",
+        close: "Fechar resumo",
+    },
+    filters: FilterMessages {
+        search_placeholder: "Buscar por nome, e-mail ou documento",
+        search_label: "Buscar usuários",
+        role_label: "Filtrar por função",
+        all_roles: "Todas as funções",
+        attendance_label: "Filtrar por participação",
+        all_attendance: "Toda participação",
+        certificates_label: "Filtrar por certificados",
+        all_certificates: "Todos os certificados",
+        no_certificates: "Sem certificados",
+        one_certificate: "Um certificado",
+        both_certificates: "Ambos os certificados",
+        clear: "Limpar filtros",
+    },
+    pager: PagerMessages {
+        label: "Paginação",
+        previous: "Anterior",
+        next: "Próxima",
+    },
+    errors: ErrorPageMessages {
+        forbidden_title: "Acesso negado",
+        forbidden_text: "Você não tem permissão para ver esta página.",
+        not_found_title: "Página não encontrada",
+        not_found_text: "Desculpe, a página que você procura não existe.",
+        back_home: "Voltar ao início",
+    },
+};
@@ -1,9 +1,18 @@
 mod components;
+mod config;
+mod contexts;
+mod csv;
+mod event;
+#[cfg(feature = "dev-fixtures")]
+mod fixtures;
+mod form;
+mod i18n;
 mod routes;
 mod services;
 mod types;
 mod utils;
 
+use contexts::{AuthProvider, I18nProvider, UserCacheProvider};
 use routes::{switch, Route};
 use yew::prelude::*;
 use yew_router::prelude::*;
@@ -11,13 +20,23 @@ use yew_router::prelude::*;
 #[function_component(App)]
 fn app() -> Html {
     html! {
-        <BrowserRouter>
-            <Switch<Route> render={switch} />
-        </BrowserRouter>
+        <I18nProvider>
+            <BrowserRouter>
+                <AuthProvider>
+                    <UserCacheProvider>
+                        <Switch<Route> render={switch} />
+                    </UserCacheProvider>
+                </AuthProvider>
+            </BrowserRouter>
+        </I18nProvider>
     }
 }
 
 fn main() {
     console_log::init_with_level(log::Level::Debug).expect("Failed to init logger");
-    yew::Renderer::<App>::new().render();
+    // Every request needs the API URL, so it is resolved before rendering
+    wasm_bindgen_futures::spawn_local(async {
+        config::load().await;
+        yew::Renderer::<App>::new().render();
+    });
 }
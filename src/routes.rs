@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
 use crate::components::*;
+use crate::types::ADMIN_ROLES;
 
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
@@ -19,6 +21,8 @@ pub enum Route {
     AdminPanel,
     #[at("/admin/registro")]
     AdminRegister,
+    #[at("/admin/importar")]
+    AdminImport,
     #[at("/admin/usuario/:id")]
     AdminUpdate { id: String },
     #[not_found]
@@ -26,6 +30,35 @@ pub enum Route {
     NotFound,
 }
 
+// Query string of the login page: `expired` shows the session expired notice
+// and `redirect` is the path to return to after signing in again
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoginQuery {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub expired: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<String>,
+}
+
+impl LoginQuery {
+    // Only same-app paths that match a page are accepted as return targets.
+    // Backslashes are refused since browsers read "/\host" like "//host", and
+    // the login page since returning there would just show the form again
+    pub fn redirect_path(&self) -> Option<&str> {
+        self.redirect
+            .as_deref()
+            .filter(|path| path.starts_with('/') && !path.starts_with("//"))
+            .filter(|path| !path.contains('\\'))
+            .filter(|path| {
+                let path = path.split(['?', '#']).next().unwrap_or_default();
+                !matches!(
+                    Route::recognize(path),
+                    None | Some(Route::NotFound | Route::Login)
+                )
+            })
+    }
+}
+
 pub fn switch(route: Route) -> Html {
     match route {
         // Public routes
@@ -46,19 +79,80 @@ pub fn switch(route: Route) -> Html {
             </ProtectedRoute>
         },
         Route::AdminPanel => html! {
-            <ProtectedRoute>
+            <ProtectedRoute roles={ADMIN_ROLES}>
                 <AdminPanel />
             </ProtectedRoute>
         },
         Route::AdminRegister => html! {
-            <ProtectedRoute>
+            <ProtectedRoute roles={ADMIN_ROLES}>
                 <AdminRegister />
             </ProtectedRoute>
         },
+        Route::AdminImport => html! {
+            <ProtectedRoute roles={ADMIN_ROLES}>
+                <AdminImport />
+            </ProtectedRoute>
+        },
         Route::AdminUpdate { id } => html! {
-            <ProtectedRoute>
+            <ProtectedRoute roles={ADMIN_ROLES}>
                 <AdminUpdate user_id={id} />
             </ProtectedRoute>
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn redirect(path: &str) -> Option<String> {
+        LoginQuery {
+            expired: true,
+            redirect: Some(path.to_string()),
+        }
+        .redirect_path()
+        .map(str::to_string)
+    }
+
+    #[test]
+    fn accepts_app_pages() {
+        assert_eq!(redirect("/perfil").as_deref(), Some("/perfil"));
+        assert_eq!(
+            redirect("/admin/usuario/42").as_deref(),
+            Some("/admin/usuario/42")
+        );
+    }
+
+    #[test]
+    fn keeps_the_query_string() {
+        assert_eq!(
+            redirect("/admin?q=ana&page=2").as_deref(),
+            Some("/admin?q=ana&page=2")
+        );
+        assert_eq!(
+            redirect("/perfil#password").as_deref(),
+            Some("/perfil#password")
+        );
+    }
+
+    #[test]
+    fn rejects_other_hosts() {
+        assert_eq!(redirect("//evil.example"), None);
+        assert_eq!(redirect("/\\evil.example"), None);
+        assert_eq!(redirect("https://evil.example/perfil"), None);
+        assert_eq!(redirect("perfil"), None);
+    }
+
+    #[test]
+    fn rejects_unknown_pages_and_login() {
+        assert_eq!(redirect("/no-existe"), None);
+        assert_eq!(redirect("/404"), None);
+        assert_eq!(redirect("/ingreso"), None);
+        assert_eq!(redirect("/ingreso?expired=true&redirect=%2Fperfil"), None);
+    }
+
+    #[test]
+    fn no_redirect_without_a_path() {
+        assert_eq!(LoginQuery::default().redirect_path(), None);
+    }
+}
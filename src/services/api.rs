@@ -1,192 +1,324 @@
+use crate::config;
+use crate::event::EventContent;
+use crate::services::auth::AuthService;
+use crate::services::error::ApiError;
 use crate::types::*;
-use gloo_net::http::Request;
+use gloo_net::http::{Method, Request, Response};
 
 pub struct ApiService;
 
 impl ApiService {
-    fn get_base_url() -> String {
-        if cfg!(debug_assertions) {
-            "http://localhost:8000".to_string()
-        } else {
-            "https://apisimposiorevsalud.univsalud.online".to_string()
+    fn get_base_url() -> &'static str {
+        config::api_base_url()
+    }
+
+    // Requests for endpoints that must not carry credentials (login, register)
+    fn public_request(method: Method, path: &str) -> Request {
+        let url = format!("{}{}", Self::get_base_url(), path);
+        Request::new(&url).method(method)
+    }
+
+    // Requests for protected endpoints, carrying the stored session token
+    fn request(method: Method, path: &str) -> Request {
+        let request = Self::public_request(method, path);
+
+        match AuthService::get_token() {
+            Some(token) => request.header("Authorization", &format!("Bearer {}", token)),
+            None => request,
         }
     }
 
-    pub async fn login(data: LoginRequest) -> Result<String, String> {
-        let url = format!("{}/auth/login", Self::get_base_url());
+    // Sends a request built by `request`, ending the session if the backend
+    // answers 401 to a token we sent. The token is checked once the response
+    // arrives, so a session another request already ended is left alone
+    async fn send(request: Request) -> Result<Response, ApiError> {
+        let response = request.send().await?;
+
+        if response.status() == 401 && AuthService::get_token().is_some() {
+            AuthService::expire_session();
+        }
+
+        Ok(response)
+    }
 
-        let response = Request::post(&url)
+    pub async fn login(data: LoginRequest) -> Result<String, ApiError> {
+        let response = Self::public_request(Method::POST, "/auth/login")
             .header("Content-Type", "application/json")
-            .json(&data)
-            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .json(&data)?
             .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .await?;
 
         if response.ok() {
-            let result: serde_json::Value = response
-                .json()
-                .await
-                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            let result: serde_json::Value = response.json().await?;
 
             Ok(result["token"].as_str().unwrap_or("").to_string())
         } else {
-            Err(format!("Login failed: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn register(data: RegisterRequest) -> Result<String, String> {
-        let url = format!("{}/auth/register", Self::get_base_url());
-
-        let response = Request::post(&url)
+    pub async fn register(data: RegisterRequest) -> Result<String, ApiError> {
+        let response = Self::public_request(Method::POST, "/auth/register")
             .header("Content-Type", "application/json")
-            .json(&data)
-            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .json(&data)?
             .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .await?;
 
         if response.ok() {
-            let result: serde_json::Value = response
-                .json()
-                .await
-                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            let result: serde_json::Value = response.json().await?;
 
             Ok(result["message"].as_str().unwrap_or("Success").to_string())
         } else {
-            Err(format!("Registration failed: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn update_user(data: UpdateUserRequest) -> Result<String, String> {
-        let url = format!("{}/user/update", Self::get_base_url());
+    // Home page content served by the backend at `path`
+    pub async fn get_event_content(path: &str) -> Result<EventContent, ApiError> {
+        let response = Self::public_request(Method::GET, path).send().await?;
 
-        let response = Request::put(&url)
+        if response.ok() {
+            Ok(response.json().await?)
+        } else {
+            Err(ApiError::from_response(response).await)
+        }
+    }
+
+    pub async fn get_current_user() -> Result<User, ApiError> {
+        let request = Self::request(Method::GET, "/user/me");
+        let response = Self::send(request).await?;
+
+        if response.ok() {
+            let user: User = response.json().await?;
+            Ok(user)
+        } else {
+            Err(ApiError::from_response(response).await)
+        }
+    }
+
+    pub async fn update_user(data: UpdateUserRequest) -> Result<String, ApiError> {
+        let request = Self::request(Method::PUT, "/user/update")
             .header("Content-Type", "application/json")
-            .json(&data)
-            .map_err(|e| format!("Failed to serialize request: {}", e))?
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .json(&data)?;
+        let response = Self::send(request).await?;
 
         if response.ok() {
             Ok("Profile updated successfully".to_string())
         } else {
-            Err(format!("Update failed: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn get_users() -> Result<Vec<User>, String> {
-        let url = format!("{}/admin/users", Self::get_base_url());
-
-        let response = Request::get(&url)
+    // Not sent through `send`: the backend answers 401 to a wrong current
+    // password, which must not end the session. It only does when the token
+    // itself has expired
+    pub async fn change_password(data: ChangePasswordRequest) -> Result<String, ApiError> {
+        let response = Self::request(Method::PUT, "/user/password")
+            .header("Content-Type", "application/json")
+            .json(&data)?
             .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .await?;
+
+        if response.status() == 401 && AuthService::current_user().is_none() {
+            AuthService::expire_session();
+        }
 
         if response.ok() {
-            let users: Vec<User> = response
-                .json()
-                .await
-                .map_err(|e| format!("Failed to parse response: {}", e))?;
-            Ok(users)
+            Ok("Password changed successfully".to_string())
         } else {
-            Err(format!("Failed to get users: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn admin_get_users() -> Result<Vec<User>, String> {
-        Self::get_users().await
+    pub async fn admin_get_users_page(
+        query: &UserQuery,
+        limit: u32,
+    ) -> Result<Page<User>, ApiError> {
+        let params = query.to_params(limit);
+        let request = Self::request(Method::GET, "/admin/users")
+            .query(params.iter().map(|(key, value)| (*key, value.as_str())));
+        let response = Self::send(request).await?;
+
+        if response.ok() {
+            let page = query.current_page();
+            match response.json::<PageResponse<User>>().await? {
+                PageResponse::Paged { items, total } => Ok(Page {
+                    items,
+                    total,
+                    page,
+                    limit,
+                }),
+                // Unpaginated backend: filter and slice the full list locally
+                PageResponse::Plain(users) => {
+                    let users = query.apply(&users);
+                    let total = users.len() as u64;
+                    // The page number comes from the URL, so it may be huge
+                    let start = (page as usize - 1).saturating_mul(limit as usize);
+                    let items = users.into_iter().skip(start).take(limit as usize).collect();
+                    Ok(Page {
+                        items,
+                        total,
+                        page,
+                        limit,
+                    })
+                }
+            }
+        } else {
+            Err(ApiError::from_response(response).await)
+        }
     }
 
-    pub async fn admin_get_user(user_id: &str) -> Result<User, String> {
-        let url = format!("{}/admin/users/{}", Self::get_base_url(), user_id);
+    // Every user matching the filters, page by page, e.g. for exports
+    pub async fn admin_get_all_users(query: &UserQuery, limit: u32) -> Result<Vec<User>, ApiError> {
+        let mut users = Vec::new();
+        let mut page = 1;
+        loop {
+            let result = Self::admin_get_users_page(&query.with_page(page), limit).await?;
+            let done = result.items.is_empty()
+                || users.len() + result.items.len() >= result.total as usize;
+            users.extend(result.items);
+            if done {
+                return Ok(users);
+            }
+            page += 1;
+        }
+    }
 
-        let response = Request::get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+    pub async fn admin_get_user(user_id: &str) -> Result<User, ApiError> {
+        let path = format!("/admin/users/{}", user_id);
+
+        let request = Self::request(Method::GET, &path);
+        let response = Self::send(request).await?;
 
         if response.ok() {
-            let user: User = response
-                .json()
-                .await
-                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            let user: User = response.json().await?;
             Ok(user)
         } else {
-            Err(format!("Failed to get user: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn admin_update_user(data: AdminUpdateUserRequest) -> Result<String, String> {
-        let url = format!("{}/admin/users/{}", Self::get_base_url(), data.id);
+    pub async fn admin_update_user(data: AdminUpdateUserRequest) -> Result<String, ApiError> {
+        let path = format!("/admin/users/{}", data.id);
 
-        let response = Request::put(&url)
+        let request = Self::request(Method::PUT, &path)
             .header("Content-Type", "application/json")
-            .json(&data)
-            .map_err(|e| format!("Failed to serialize request: {}", e))?
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+            .json(&data)?;
+        let response = Self::send(request).await?;
 
         if response.ok() {
             Ok("User updated successfully".to_string())
         } else {
-            Err(format!("Update failed: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn delete_user(data: DeleteUserRequest) -> Result<String, String> {
-        let url = format!("{}/admin/users/delete", Self::get_base_url());
+    // The backend soft-deletes, so the user can be brought back with
+    // `restore_user` for a while
+    pub async fn delete_user(user_id: &str) -> Result<String, ApiError> {
+        let path = format!("/admin/users/{}", user_id);
 
-        let response = Request::delete(&url)
-            .header("Content-Type", "application/json")
-            .json(&data)
-            .map_err(|e| format!("Failed to serialize request: {}", e))?
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+        let request = Self::request(Method::DELETE, &path);
+        let response = Self::send(request).await?;
 
         if response.ok() {
             Ok("User deleted successfully".to_string())
         } else {
-            Err(format!("Delete failed: {}", response.status()))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn generate_horizontal_cert() -> Result<String, String> {
-        let url = format!("{}/certificates/horizontal", Self::get_base_url());
+    pub async fn restore_user(user_id: &str) -> Result<String, ApiError> {
+        let path = format!("/admin/users/{}/restore", user_id);
 
-        let response = Request::post(&url)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+        let request = Self::request(Method::POST, &path);
+        let response = Self::send(request).await?;
 
         if response.ok() {
-            Ok("Horizontal certificate generated".to_string())
+            Ok("User restored successfully".to_string())
         } else {
-            Err(format!(
-                "Certificate generation failed: {}",
-                response.status()
-            ))
+            Err(ApiError::from_response(response).await)
         }
     }
 
-    pub async fn generate_vertical_cert() -> Result<String, String> {
-        let url = format!("{}/certificates/vertical", Self::get_base_url());
+    pub async fn generate_horizontal_cert(full_name: &str) -> Result<CertificateFile, ApiError> {
+        Self::generate_cert(CertificateOrientation::Horizontal, full_name).await
+    }
 
-        let response = Request::post(&url)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?;
+    pub async fn generate_vertical_cert(full_name: &str) -> Result<CertificateFile, ApiError> {
+        Self::generate_cert(CertificateOrientation::Vertical, full_name).await
+    }
+
+    async fn generate_cert(
+        orientation: CertificateOrientation,
+        full_name: &str,
+    ) -> Result<CertificateFile, ApiError> {
+        let path = format!("/certificates/{}", orientation.as_str());
+
+        let request = Self::request(Method::POST, &path);
+        let response = Self::send(request).await?;
 
         if response.ok() {
-            Ok("Vertical certificate generated".to_string())
+            let content_type = response
+                .headers()
+                .get("Content-Type")
+                .unwrap_or_else(|| "application/pdf".to_string());
+            let filename = response
+                .headers()
+                .get("Content-Disposition")
+                .and_then(|disposition| Self::disposition_filename(&disposition))
+                .unwrap_or_else(|| {
+                    Self::certificate_filename(full_name, orientation, &content_type)
+                });
+            let bytes = response.binary().await?;
+
+            Ok(CertificateFile {
+                bytes,
+                content_type,
+                filename,
+            })
+        } else {
+            Err(ApiError::from_response(response).await)
+        }
+    }
+
+    // `attachment; filename="name.pdf"` -> `name.pdf`
+    fn disposition_filename(disposition: &str) -> Option<String> {
+        disposition
+            .split(';')
+            .map(str::trim)
+            .find_map(|part| part.strip_prefix("filename="))
+            .map(|name| name.trim_matches('"').to_string())
+            .filter(|name| !name.is_empty())
+    }
+
+    // `constancia_maria_perez_horizontal.pdf`
+    fn certificate_filename(
+        full_name: &str,
+        orientation: CertificateOrientation,
+        content_type: &str,
+    ) -> String {
+        let name = full_name
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .filter(|c| c.is_alphanumeric())
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("_");
+        let extension = if content_type.starts_with("image/png") {
+            "png"
+        } else {
+            "pdf"
+        };
+
+        if name.is_empty() {
+            format!("constancia_{}.{}", orientation.as_str(), extension)
         } else {
-            Err(format!(
-                "Certificate generation failed: {}",
-                response.status()
-            ))
+            format!("constancia_{}_{}.{}", name, orientation.as_str(), extension)
         }
     }
 }
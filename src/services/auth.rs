@@ -1,4 +1,11 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
 use gloo_storage::{LocalStorage, Storage};
+use yew_router::history::{BrowserHistory, History};
+use yew_router::Routable;
+
+use crate::routes::{LoginQuery, Route};
+use crate::types::Claims;
 
 const TOKEN_KEY: &str = "auth_token";
 const USER_KEY: &str = "current_user";
@@ -6,8 +13,16 @@ const USER_KEY: &str = "current_user";
 pub struct AuthService;
 
 impl AuthService {
-    pub fn set_token(token: String) {
+    // Stores the session; a token whose claims cannot be read is rejected,
+    // since the app would treat it as logged out (and expired) right away
+    pub fn set_token(token: String) -> bool {
+        let Some(claims) = Self::decode_claims(&token) else {
+            log::warn!("Session token has no readable claims");
+            return false;
+        };
+        Self::set_user_info(&claims);
         let _ = LocalStorage::set(TOKEN_KEY, token);
+        true
     }
 
     pub fn get_token() -> Option<String> {
@@ -18,8 +33,20 @@ impl AuthService {
         LocalStorage::delete(TOKEN_KEY);
     }
 
-    pub fn is_authenticated() -> bool {
-        Self::get_token().is_some()
+    // Claims of the stored session, if there is one and it has not expired
+    pub fn current_user() -> Option<Claims> {
+        let token = Self::get_token()?;
+        Self::get_user_info()
+            .or_else(|| Self::decode_claims(&token))
+            .filter(|claims| !claims.is_expired())
+    }
+
+    // Reads the payload segment of a JWT (base64url JSON); the signature is
+    // not checked here
+    pub fn decode_claims(token: &str) -> Option<Claims> {
+        let payload = token.split('.').nth(1)?;
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice(&bytes).ok()
     }
 
     pub fn logout() {
@@ -27,12 +54,144 @@ impl AuthService {
         LocalStorage::delete(USER_KEY);
     }
 
-    // You can add methods to store/retrieve user info if needed
-    pub fn set_user_info(user: serde_json::Value) {
+    // Called when the backend rejects the stored token: clears the session and
+    // sends the user to the login page, remembering where they were
+    pub fn expire_session() {
+        let location = gloo_utils::window().location();
+        let current = format!(
+            "{}{}",
+            location.pathname().unwrap_or_default(),
+            location.search().unwrap_or_default()
+        );
+
+        Self::logout();
+
+        let query = LoginQuery {
+            expired: true,
+            redirect: Some(current),
+        };
+        if let Err(error) = BrowserHistory::new().push_with_query(Route::Login.to_path(), query) {
+            log::error!("Failed to redirect to login: {}", error);
+        }
+    }
+
+    pub fn set_user_info(user: &Claims) {
         let _ = LocalStorage::set(USER_KEY, user);
     }
 
-    pub fn get_user_info() -> Option<serde_json::Value> {
+    pub fn get_user_info() -> Option<Claims> {
         LocalStorage::get(USER_KEY).ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use base64::engine::general_purpose::URL_SAFE;
+
+    use super::*;
+    use crate::types::Role;
+
+    const HEADER: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9";
+
+    fn token(payload: &str) -> String {
+        format!("{}.{}.signature", HEADER, URL_SAFE_NO_PAD.encode(payload))
+    }
+
+    #[test]
+    fn reads_the_id_claim() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"id":"7","email":"ana@example.com","role":"staff","exp":1700000000}"#,
+        ))
+        .unwrap();
+        assert_eq!(claims.id, "7");
+        assert_eq!(claims.email, "ana@example.com");
+        assert_eq!(claims.role, Role::Staff);
+        assert_eq!(claims.exp, 1700000000);
+    }
+
+    #[test]
+    fn falls_back_to_sub() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"sub":"abc","email":"a@b.co","role":"attendee","exp":1}"#,
+        ))
+        .unwrap();
+        assert_eq!(claims.id, "abc");
+    }
+
+    #[test]
+    fn prefers_id_over_sub() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"sub":"abc","id":"7","email":"a@b.co","role":"attendee","exp":1}"#,
+        ))
+        .unwrap();
+        assert_eq!(claims.id, "7");
+    }
+
+    #[test]
+    fn accepts_numeric_ids() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"sub":42,"email":"a@b.co","role":"attendee","exp":1}"#,
+        ))
+        .unwrap();
+        assert_eq!(claims.id, "42");
+    }
+
+    #[test]
+    fn accepts_unknown_roles() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"id":"7","email":"a@b.co","role":"superuser","exp":1}"#,
+        ))
+        .unwrap();
+        assert_eq!(claims.role, Role::Unknown);
+        assert!(!claims.is_admin());
+    }
+
+    #[test]
+    fn accepts_padded_payloads() {
+        // Sized so the padded encoding ends in "="
+        let payload = r#"{"id":"7","email":"ana@example.com","role":"staff","exp":1}"#;
+        let encoded = URL_SAFE.encode(payload);
+        assert!(encoded.ends_with('='));
+        let claims = AuthService::decode_claims(&format!("{}.{}.sig", HEADER, encoded)).unwrap();
+        assert_eq!(claims.id, "7");
+    }
+
+    #[test]
+    fn rejects_incomplete_claims() {
+        let missing = [
+            r#"{"email":"a@b.co","role":"staff","exp":1}"#,
+            r#"{"id":"7","email":"a@b.co","role":"staff"}"#,
+            r#"{"id":"7","email":"a@b.co","exp":1}"#,
+            r#"{"id":"7","role":"staff","exp":1}"#,
+        ];
+        for payload in missing {
+            assert_eq!(
+                AuthService::decode_claims(&token(payload)),
+                None,
+                "{}",
+                payload
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_non_jwt_strings() {
+        assert_eq!(AuthService::decode_claims(""), None);
+        assert_eq!(AuthService::decode_claims("not-a-token"), None);
+        assert_eq!(AuthService::decode_claims("a.%%%.c"), None);
+        assert_eq!(
+            AuthService::decode_claims(&format!("{}.{}.c", HEADER, URL_SAFE_NO_PAD.encode("[]"))),
+            None
+        );
+    }
+
+    #[test]
+    fn stored_claims_read_back() {
+        let claims = AuthService::decode_claims(&token(
+            r#"{"sub":42,"email":"a@b.co","role":"webmaster","exp":1}"#,
+        ))
+        .unwrap();
+        let stored = serde_json::to_string(&claims).unwrap();
+        assert_eq!(serde_json::from_str::<Claims>(&stored).unwrap(), claims);
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use gloo_net::http::Response;
+use serde_json::Value;
+
+use crate::i18n;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    Network(String),
+    Serialization(String),
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict(String),
+    Validation {
+        message: String,
+        fields: Vec<FieldError>,
+    },
+    Server {
+        status: u16,
+        message: String,
+    },
+}
+
+impl ApiError {
+    // Builds the error for a non-2xx response, reading the backend's JSON
+    // error body ({ "message" | "error", "fields" | "errors" }) when present
+    pub async fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let body = response.json::<Value>().await.unwrap_or(Value::Null);
+
+        Self::from_status(status, &body)
+    }
+
+    pub fn from_status(status: u16, body: &Value) -> Self {
+        let message = body["message"]
+            .as_str()
+            .or_else(|| body["error"].as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        match status {
+            400 | 422 => ApiError::Validation {
+                message,
+                fields: Self::parse_fields(body),
+            },
+            401 => ApiError::Unauthorized,
+            403 => ApiError::Forbidden,
+            404 => ApiError::NotFound,
+            409 => ApiError::Conflict(message),
+            _ => ApiError::Server { status, message },
+        }
+    }
+
+    // Accepts either { "field": "message" } / { "field": ["message", ...] }
+    // objects or [{ "field": ..., "message": ... }] arrays
+    fn parse_fields(body: &Value) -> Vec<FieldError> {
+        let details = if body["fields"].is_null() {
+            &body["errors"]
+        } else {
+            &body["fields"]
+        };
+
+        match details {
+            Value::Object(map) => map
+                .iter()
+                .map(|(field, value)| FieldError {
+                    field: field.clone(),
+                    message: match value {
+                        Value::String(message) => message.clone(),
+                        Value::Array(messages) => messages
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        other => other.to_string(),
+                    },
+                })
+                .collect(),
+            Value::Array(items) => items
+                .iter()
+                .filter_map(|item| {
+                    Some(FieldError {
+                        field: item["field"].as_str()?.to_string(),
+                        message: item["message"].as_str().unwrap_or_default().to_string(),
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let t = &i18n::messages().api;
+        match self {
+            ApiError::Network(_) => f.write_str(t.network),
+            ApiError::Serialization(_) => f.write_str(t.serialization),
+            ApiError::Unauthorized => f.write_str(t.unauthorized),
+            ApiError::Forbidden => f.write_str(t.forbidden),
+            ApiError::NotFound => f.write_str(t.not_found),
+            ApiError::Conflict(message) if !message.is_empty() => {
+                f.write_str(&(t.conflict_with)(message))
+            }
+            ApiError::Conflict(_) => f.write_str(t.conflict),
+            ApiError::Validation { fields, .. } if !fields.is_empty() => {
+                let details = fields
+                    .iter()
+                    .map(|error| format!("{}: {}", error.field, error.message))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                f.write_str(&(t.invalid_fields)(&details))
+            }
+            ApiError::Validation { message, .. } if !message.is_empty() => {
+                f.write_str(&(t.invalid_with)(message))
+            }
+            ApiError::Validation { .. } => f.write_str(t.invalid),
+            ApiError::Server { status, .. } => f.write_str(&(t.server)(*status)),
+        }
+    }
+}
+
+impl From<gloo_net::Error> for ApiError {
+    fn from(error: gloo_net::Error) -> Self {
+        match error {
+            gloo_net::Error::SerdeError(e) => ApiError::Serialization(e.to_string()),
+            other => ApiError::Network(other.to_string()),
+        }
+    }
+}
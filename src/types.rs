@@ -1,4 +1,9 @@
-use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::i18n::{self, Messages};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterRequest {
@@ -6,9 +11,26 @@ pub struct RegisterRequest {
     pub full_name: String,
     pub identification: String,
     pub password: String,
-    pub role: String,
-    pub presentation: String,
-    pub attendance: String,
+    pub role: Role,
+    // Only for speakers
+    #[serde(with = "optional_hours")]
+    pub presentation: Option<Hours>,
+    pub attendance: Attendance,
+}
+
+// Initial state of the registration forms
+impl Default for RegisterRequest {
+    fn default() -> Self {
+        RegisterRequest {
+            email: String::new(),
+            full_name: String::new(),
+            identification: String::new(),
+            password: String::new(),
+            role: Role::Attendee,
+            presentation: None,
+            attendance: Attendance::Remote,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,11 +39,25 @@ pub struct LoginRequest {
     pub password: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Only the fields that changed are sent
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateUserRequest {
-    pub email: String,
-    pub password: String,
-    pub attendance: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendance: Option<Attendance>,
+}
+
+impl UpdateUserRequest {
+    pub fn is_empty(&self) -> bool {
+        self.email.is_none() && self.attendance.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,48 +67,691 @@ pub struct AdminUpdateUserRequest {
     pub full_name: String,
     pub identification: String,
     pub password: String,
-    pub role: String,
-    pub presentation: String,
-    pub attendance: String,
+    pub role: Role,
+    #[serde(with = "optional_hours")]
+    pub presentation: Option<Hours>,
+    pub attendance: Attendance,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_generated: Option<CertificateStatus>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeleteUserRequest {
-    pub email: String,
-    pub full_name: String,
-    pub identification: String,
-    pub password: String,
-    pub role: String,
-    pub presentation: String,
-    pub attendance: String,
+impl AdminUpdateUserRequest {
+    // Request that keeps every field of `user` as is (password unchanged)
+    pub fn from_user(user: &User) -> Self {
+        AdminUpdateUserRequest {
+            id: user.id.clone(),
+            email: user.email.clone(),
+            full_name: user.full_name.clone(),
+            identification: user.identification.clone(),
+            password: String::new(),
+            role: user.role(),
+            presentation: user.speaker_hours(),
+            attendance: user.attendance,
+            cert_generated: None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub id: String,
     pub email: String,
     pub full_name: String,
     pub identification: String,
+    // Speaker hours travel inside the role; a top-level `presentation`
+    // sent by the backend is ignored
     pub role: UserRole,
-    pub presentation: Option<String>,
-    pub attendance: String,
+    pub attendance: Attendance,
     pub cert_generated: CertificateStatus,
+    // Set by the organizers once attendance is verified; older backends do
+    // not send it, in which case the backend decides on generation
+    #[serde(default)]
+    pub attendance_confirmed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl User {
+    // Copy of the user with the changes of an admin update applied
+    pub fn with_update(&self, update: &AdminUpdateUserRequest) -> User {
+        let role = match update.role {
+            Role::Speaker => UserRole::Speaker {
+                speaker: SpeakerInfo {
+                    presentation: update.presentation.unwrap_or_default(),
+                },
+            },
+            role => UserRole::Simple(role),
+        };
+
+        User {
+            email: update.email.clone(),
+            full_name: update.full_name.clone(),
+            identification: update.identification.clone(),
+            role,
+            attendance: update.attendance,
+            cert_generated: update
+                .cert_generated
+                .clone()
+                .unwrap_or_else(|| self.cert_generated.clone()),
+            ..self.clone()
+        }
+    }
+
+    pub fn can_generate_certificates(&self) -> bool {
+        self.attendance_confirmed != Some(false)
+    }
+
+    pub fn role(&self) -> Role {
+        match &self.role {
+            UserRole::Simple(role) => *role,
+            UserRole::Speaker { .. } => Role::Speaker,
+        }
+    }
+
+    pub fn speaker_hours(&self) -> Option<Hours> {
+        match &self.role {
+            UserRole::Speaker { speaker } => Some(speaker.presentation),
+            UserRole::Simple(_) => None,
+        }
+    }
+}
+
+// One page of a paginated listing
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u32,
+    pub limit: u32,
+}
+
+impl<T> Page<T> {
+    pub fn total_pages(&self) -> u32 {
+        if self.limit == 0 {
+            return 1;
+        }
+        (self.total.div_ceil(self.limit as u64) as u32).max(1)
+    }
+}
+
+// Body of the paginated users endpoint. Backends without pagination answer
+// with a plain array
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PageResponse<T> {
+    Paged {
+        #[serde(alias = "users", alias = "data")]
+        items: Vec<T>,
+        total: u64,
+    },
+    Plain(Vec<T>),
+}
+
+// Value with a fixed key on the wire and a label for the UI, e.g. the
+// options of a <select>
+pub trait Choice: Copy + PartialEq + 'static {
+    const ALL: &'static [Self];
+
+    fn key(&self) -> &'static str;
+    fn label(&self) -> &'static str;
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|choice| choice.key() == key)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    Attendee,
+    Speaker,
+    Staff,
+    Webmaster,
+    // Any other value sent by the backend, so one odd record does not make
+    // the whole listing fail to load. Never offered in the forms
+    #[serde(other)]
+    Unknown,
+}
+
+impl Choice for Role {
+    const ALL: &'static [Self] = &[Role::Attendee, Role::Speaker, Role::Staff, Role::Webmaster];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Role::Attendee => "attendee",
+            Role::Speaker => "speaker",
+            Role::Staff => "staff",
+            Role::Webmaster => "webmaster",
+            Role::Unknown => "unknown",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        self.label_in(i18n::messages())
+    }
+}
+
+impl Role {
+    pub fn label_in(&self, messages: &'static Messages) -> &'static str {
+        match self {
+            Role::Attendee => messages.roles.attendee,
+            Role::Speaker => messages.roles.speaker,
+            Role::Staff => messages.roles.staff,
+            Role::Webmaster => messages.roles.webmaster,
+            Role::Unknown => messages.roles.unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Attendance {
+    #[default]
+    Remote,
+    Presential,
+    // Same as `Role::Unknown`
+    #[serde(other)]
+    Unknown,
+}
+
+impl Choice for Attendance {
+    const ALL: &'static [Self] = &[Attendance::Remote, Attendance::Presential];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Attendance::Remote => "remote",
+            Attendance::Presential => "presential",
+            Attendance::Unknown => "unknown",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        self.label_in(i18n::messages())
+    }
+}
+
+impl Attendance {
+    pub fn label_in(&self, messages: &'static Messages) -> &'static str {
+        match self {
+            Attendance::Remote => messages.attendance.remote,
+            Attendance::Presential => messages.attendance.presential,
+            Attendance::Unknown => messages.attendance.unknown,
+        }
+    }
+}
+
+// Speakers come as { "speaker": { "presentation": ... } }, every other role
+// as a plain string
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum UserRole {
-    Simple(String),
+    Simple(Role),
     Speaker { speaker: SpeakerInfo },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpeakerInfo {
-    pub presentation: String,
+    pub presentation: Hours,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Length of a presentation, in half-hour steps from 0 to `Hours::MAX`.
+// Travels as a string ("1.5"), which is what the backend stores
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hours(u32);
+
+impl Hours {
+    pub const MAX: Hours = Hours(80);
+
+    fn from_f64(hours: f64) -> Result<Hours, String> {
+        let t = &i18n::messages().validation;
+        if !hours.is_finite() {
+            return Err(t.hours_not_number.to_string());
+        }
+        if hours < 0.0 {
+            return Err(t.hours_negative.to_string());
+        }
+        if hours > Hours::MAX.as_f64() {
+            return Err((t.hours_too_many)(&Hours::MAX.to_string()));
+        }
+        let half_hours = hours * 2.0;
+        if half_hours.fract() != 0.0 {
+            return Err(t.hours_not_half.to_string());
+        }
+        Ok(Hours(half_hours as u32))
+    }
+
+    // Accepts "2", "1.5" and "1,5"; empty means 0
+    pub fn parse(value: &str) -> Result<Hours, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(Hours::default());
+        }
+        let hours = value
+            .replace(',', ".")
+            .parse::<f64>()
+            .map_err(|_| i18n::messages().validation.hours_not_number.to_string())?;
+        Self::from_f64(hours)
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.0 as f64 / 2.0
+    }
+
+    // Machine format, as sent to the backend and shown in form inputs
+    pub fn key(&self) -> String {
+        if self.0.is_multiple_of(2) {
+            (self.0 / 2).to_string()
+        } else {
+            format!("{}.5", self.0 / 2)
+        }
+    }
+}
+
+// Display format with the decimal separator of the current language:
+// "2", "1,5" or "1.5"
+impl fmt::Display for Hours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = i18n::messages().decimal_separator;
+        f.write_str(&self.key().replace('.', &separator.to_string()))
+    }
+}
+
+impl Serialize for Hours {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.key())
+    }
+}
+
+// Older records may hold numbers or free text; anything unreadable counts
+// as 0 instead of making the whole user list fail to load
+impl<'de> Deserialize<'de> for Hours {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(f64),
+            Text(String),
+        }
+
+        let result = match Raw::deserialize(deserializer)? {
+            Raw::Number(hours) => Hours::from_f64(hours),
+            Raw::Text(text) => Hours::parse(&text),
+        };
+        Ok(result.unwrap_or_else(|error| {
+            log::warn!("Ignoring invalid presentation hours: {}", error);
+            Hours::default()
+        }))
+    }
+}
+
+// `presentation` of the request bodies: empty string for non-speakers
+mod optional_hours {
+    use super::Hours;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Hours>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(hours) => hours.serialize(serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Hours>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Ok((!text.trim().is_empty()).then(|| Hours::parse(&text).unwrap_or_default()))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct CertificateStatus {
     pub horizontal: bool,
     pub vertical: bool,
 }
+
+impl CertificateStatus {
+    pub fn is_generated(&self, orientation: CertificateOrientation) -> bool {
+        match orientation {
+            CertificateOrientation::Horizontal => self.horizontal,
+            CertificateOrientation::Vertical => self.vertical,
+        }
+    }
+
+    pub fn set_generated(&mut self, orientation: CertificateOrientation) {
+        match orientation {
+            CertificateOrientation::Horizontal => self.horizontal = true,
+            CertificateOrientation::Vertical => self.vertical = true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CertificateOrientation {
+    Horizontal,
+    Vertical,
+}
+
+impl CertificateOrientation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CertificateOrientation::Horizontal => "horizontal",
+            CertificateOrientation::Vertical => "vertical",
+        }
+    }
+}
+
+// Certificate as returned by the backend (PDF or PNG)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateFile {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+    pub filename: String,
+}
+
+impl CertificateFile {
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
+}
+
+pub const ADMIN_ROLES: &[Role] = &[Role::Webmaster, Role::Staff];
+
+// Payload of the session JWT. Decoded client-side without verifying the
+// signature, so it is only used for UI decisions; the backend still
+// authorizes every request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawClaims")]
+pub struct Claims {
+    pub id: String,
+    pub email: String,
+    pub role: Role,
+    pub exp: u64,
+}
+
+// Claims as they may come in the token: the user id in `id` or the standard
+// `sub` (or both), as a string or a number
+#[derive(Deserialize)]
+struct RawClaims {
+    #[serde(default)]
+    id: Option<ClaimId>,
+    #[serde(default)]
+    sub: Option<ClaimId>,
+    email: String,
+    role: Role,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ClaimId {
+    Text(String),
+    Number(serde_json::Number),
+}
+
+impl TryFrom<RawClaims> for Claims {
+    type Error = &'static str;
+
+    fn try_from(raw: RawClaims) -> Result<Self, Self::Error> {
+        let id = match raw.id.or(raw.sub).ok_or("the token has no user id")? {
+            ClaimId::Text(id) => id,
+            ClaimId::Number(id) => id.to_string(),
+        };
+        Ok(Claims {
+            id,
+            email: raw.email,
+            role: raw.role,
+            exp: raw.exp,
+        })
+    }
+}
+
+impl Claims {
+    pub fn is_expired(&self) -> bool {
+        let now_secs = (js_sys::Date::now() / 1000.0) as u64;
+        self.exp <= now_secs
+    }
+
+    pub fn has_any_role(&self, roles: &[Role]) -> bool {
+        roles.contains(&self.role)
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.has_any_role(ADMIN_ROLES)
+    }
+}
+
+// Search, filter and sort state of the admin user table. It lives in the URL
+// query string so it survives reloads and can be shared
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserQuery {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attendance: Option<Attendance>,
+    // "none", "partial" or "complete"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub desc: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+impl UserQuery {
+    pub fn matches(&self, user: &User) -> bool {
+        let search = self
+            .q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+        let matches_search = search.is_none_or(|q| {
+            user.full_name.to_lowercase().contains(&q)
+                || user.email.to_lowercase().contains(&q)
+                || user.identification.to_lowercase().contains(&q)
+        });
+
+        let matches_role = self.role.is_none_or(|role| user.role() == role);
+        let matches_attendance = self
+            .attendance
+            .is_none_or(|attendance| user.attendance == attendance);
+
+        let generated = [user.cert_generated.horizontal, user.cert_generated.vertical]
+            .iter()
+            .filter(|generated| **generated)
+            .count();
+        let matches_cert = match self.cert.as_deref() {
+            Some("none") => generated == 0,
+            Some("partial") => generated == 1,
+            Some("complete") => generated == 2,
+            _ => true,
+        };
+
+        matches_search && matches_role && matches_attendance && matches_cert
+    }
+
+    fn compare(&self, a: &User, b: &User) -> Ordering {
+        let ordering = match self.sort.as_deref() {
+            Some("email") => a.email.to_lowercase().cmp(&b.email.to_lowercase()),
+            Some("name") => a.full_name.to_lowercase().cmp(&b.full_name.to_lowercase()),
+            Some("identification") => a.identification.cmp(&b.identification),
+            Some("role") => a.role().label().cmp(b.role().label()),
+            Some("hours") => a
+                .speaker_hours()
+                .unwrap_or_default()
+                .cmp(&b.speaker_hours().unwrap_or_default()),
+            Some("attendance") => a.attendance.key().cmp(b.attendance.key()),
+            Some("id") => a.id.cmp(&b.id),
+            _ => Ordering::Equal,
+        };
+
+        if self.desc {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    pub fn apply(&self, users: &[User]) -> Vec<User> {
+        let mut result: Vec<User> = users
+            .iter()
+            .filter(|user| self.matches(user))
+            .cloned()
+            .collect();
+        result.sort_by(|a, b| self.compare(a, b));
+        result
+    }
+
+    // Clicking the sorted column again flips the direction
+    pub fn toggle_sort(&self, column: &str) -> Self {
+        let same_column = self.sort.as_deref() == Some(column);
+        UserQuery {
+            sort: Some(column.to_string()),
+            desc: same_column && !self.desc,
+            page: None,
+            ..self.clone()
+        }
+    }
+
+    pub fn current_page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    pub fn with_page(&self, page: u32) -> Self {
+        UserQuery {
+            page: (page > 1).then_some(page),
+            ..self.clone()
+        }
+    }
+
+    // Parameters forwarded to the paginated users endpoint
+    pub fn to_params(&self, limit: u32) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("page", self.current_page().to_string()),
+            ("limit", limit.to_string()),
+        ];
+        let filters = [
+            ("q", self.q.as_deref()),
+            ("role", self.role.map(|role| role.key())),
+            (
+                "attendance",
+                self.attendance.map(|attendance| attendance.key()),
+            ),
+            ("cert", self.cert.as_deref()),
+            ("sort", self.sort.as_deref()),
+        ];
+        for (key, value) in filters {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                params.push((key, value.to_string()));
+            }
+        }
+        if self.desc {
+            params.push(("order", "desc".to_string()));
+        }
+        params
+    }
+
+    pub fn sort_indicator(&self, column: &str) -> &'static str {
+        match (self.sort.as_deref() == Some(column), self.desc) {
+            (true, false) => " ▲",
+            (true, true) => " ▼",
+            (false, _) => "",
+        }
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.q.as_deref().is_some_and(|q| !q.trim().is_empty())
+            || self.role.is_some()
+            || self.attendance.is_some()
+            || self.cert.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours(value: &str) -> Hours {
+        Hours::parse(value).unwrap()
+    }
+
+    #[test]
+    fn parses_dot_and_comma_decimals() {
+        assert_eq!(hours("2").as_f64(), 2.0);
+        assert_eq!(hours("1.5").as_f64(), 1.5);
+        assert_eq!(hours("1,5"), hours("1.5"));
+        assert_eq!(hours(" 0.5 ").as_f64(), 0.5);
+    }
+
+    #[test]
+    fn empty_means_zero() {
+        assert_eq!(hours(""), Hours::default());
+        assert_eq!(hours("   "), Hours::default());
+    }
+
+    #[test]
+    fn rejects_out_of_range_and_partial_hours() {
+        assert_eq!(hours("40"), Hours::MAX);
+        assert!(Hours::parse("40.5").is_err());
+        assert!(Hours::parse("-1").is_err());
+        assert!(Hours::parse("1.25").is_err());
+        assert!(Hours::parse("dos").is_err());
+        assert!(Hours::parse("NaN").is_err());
+        assert!(Hours::parse("inf").is_err());
+        assert!(Hours::from_f64(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn key_and_display_round_trip() {
+        for value in ["0", "0.5", "2", "12.5", "40"] {
+            let parsed = hours(value);
+            assert_eq!(parsed.key(), value);
+            assert_eq!(hours(&parsed.to_string()), parsed);
+        }
+        // Spanish is the default language
+        assert_eq!(hours("1.5").to_string(), "1,5");
+    }
+
+    #[test]
+    fn deserializes_legacy_values_leniently() {
+        let from = |json: &str| serde_json::from_str::<Hours>(json).unwrap();
+        assert_eq!(from("\"1.5\""), hours("1.5"));
+        assert_eq!(from("\"1,5\""), hours("1.5"));
+        assert_eq!(from("2"), hours("2"));
+        assert_eq!(from("2.5"), hours("2.5"));
+        // Anything unreadable counts as 0
+        assert_eq!(from("\"dos horas\""), Hours::default());
+        assert_eq!(from("1.25"), Hours::default());
+        assert_eq!(from("-3"), Hours::default());
+        assert_eq!(from("100"), Hours::default());
+    }
+
+    #[test]
+    fn serializes_as_a_string() {
+        assert_eq!(serde_json::to_string(&hours("1,5")).unwrap(), "\"1.5\"");
+    }
+
+    #[test]
+    fn request_hours_are_empty_for_non_speakers() {
+        let request = RegisterRequest::default();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["presentation"], "");
+
+        let speaker = RegisterRequest {
+            role: Role::Speaker,
+            presentation: Some(hours("3")),
+            ..RegisterRequest::default()
+        };
+        let json = serde_json::to_value(&speaker).unwrap();
+        assert_eq!(json["presentation"], "3");
+        let back: RegisterRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(back.presentation, Some(hours("3")));
+    }
+}
@@ -1,25 +1,141 @@
-pub fn validate_password(password: &str) -> bool {
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+use crate::i18n;
+
+// Characters accepted as the special character of a password
+const PASSWORD_SPECIAL_CHARS: &str = "!@#$%^&*_=+'";
+
+// Password requirements that the password does not meet, phrased to
+// complete `password_needs` in the current language; empty when it is valid
+pub fn password_issues(password: &str) -> Vec<&'static str> {
+    let t = &i18n::messages().validation;
+    let mut issues = Vec::new();
+
     // Check minimum length
     if password.len() < 8 {
-        return false;
+        issues.push(t.password_length);
     }
 
     // Check for at least one lowercase letter
-    let has_lowercase = password.chars().any(|c| c.is_ascii_lowercase());
+    if !password.chars().any(|c| c.is_ascii_lowercase()) {
+        issues.push(t.password_lowercase);
+    }
 
     // Check for at least one uppercase letter
-    let has_uppercase = password.chars().any(|c| c.is_ascii_uppercase());
+    if !password.chars().any(|c| c.is_ascii_uppercase()) {
+        issues.push(t.password_uppercase);
+    }
 
     // Check for at least one digit
-    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    if !password.chars().any(|c| c.is_ascii_digit()) {
+        issues.push(t.password_digit);
+    }
 
     // Check for at least one special character
-    let special_chars = "!@#$%^&*_=+'";
-    let has_special = password.chars().any(|c| special_chars.contains(c));
+    if !password.chars().any(|c| PASSWORD_SPECIAL_CHARS.contains(c)) {
+        issues.push(t.password_special);
+    }
+
+    issues
+}
+
+const PASSWORD_LOWERCASE: &str = "abcdefghijkmnopqrstuvwxyz";
+const PASSWORD_UPPERCASE: &str = "ABCDEFGHJKLMNPQRSTUVWXYZ";
+const PASSWORD_DIGITS: &str = "23456789";
+const PASSWORD_SPECIAL: &str = "!@#$%^&*_=+";
+
+// Fills the buffer from the browser's cryptographic random source
+fn random_bytes(buffer: &mut [u8]) -> Result<(), JsValue> {
+    gloo_utils::window()
+        .crypto()?
+        .get_random_values_with_u8_array(buffer)?;
+    Ok(())
+}
+
+fn random_index(bound: usize) -> Result<usize, JsValue> {
+    let mut bytes = [0u8; 4];
+    random_bytes(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes) as usize % bound)
+}
+
+fn random_char(charset: &str) -> Result<char, JsValue> {
+    let chars: Vec<char> = charset.chars().collect();
+    Ok(chars[random_index(chars.len())?])
+}
+
+// Random password with no `password_issues`. Look-alike characters
+// (0/O, 1/l/I) are left out since it is usually read off the screen
+pub fn generate_password(length: usize) -> Result<String, JsValue> {
+    let all = [
+        PASSWORD_LOWERCASE,
+        PASSWORD_UPPERCASE,
+        PASSWORD_DIGITS,
+        PASSWORD_SPECIAL,
+    ]
+    .concat();
 
-    has_lowercase && has_uppercase && has_digit && has_special
+    // One character of each required class, the rest from any of them
+    let mut chars = vec![
+        random_char(PASSWORD_LOWERCASE)?,
+        random_char(PASSWORD_UPPERCASE)?,
+        random_char(PASSWORD_DIGITS)?,
+        random_char(PASSWORD_SPECIAL)?,
+    ];
+    while chars.len() < length.max(8) {
+        chars.push(random_char(&all)?);
+    }
+
+    // Fisher-Yates shuffle so the required classes are not always first
+    for i in (1..chars.len()).rev() {
+        chars.swap(i, random_index(i + 1)?);
+    }
+
+    Ok(chars.into_iter().collect())
 }
 
+// Single "@" with a non-empty local part and a dotted domain, e.g. a@b.co
 pub fn validate_email(email: &str) -> bool {
-    email.contains('@') && email.contains('.') && email.len() >= 5
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+
+    email.len() >= 5
+        && !local.is_empty()
+        && !email.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+// Wraps raw bytes in a Blob and returns an object URL for it. The caller is
+// responsible for releasing it with `revoke_object_url`
+pub fn object_url(bytes: &[u8], content_type: &str) -> Result<String, JsValue> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = BlobPropertyBag::new();
+    options.set_type(content_type);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+
+    Url::create_object_url_with_blob(&blob)
+}
+
+pub fn revoke_object_url(url: &str) {
+    let _ = Url::revoke_object_url(url);
+}
+
+// Triggers a browser download of `bytes` through a temporary anchor element
+pub fn download_file(bytes: &[u8], content_type: &str, filename: &str) -> Result<(), JsValue> {
+    let url = object_url(bytes, content_type)?;
+    let anchor: HtmlAnchorElement = gloo_utils::document().create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    // Revoking right away can cancel the download in some browsers
+    let release = Closure::once_into_js(move || revoke_object_url(&url));
+    gloo_utils::window()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(release.unchecked_ref(), 1000)?;
+
+    Ok(())
 }